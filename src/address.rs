@@ -1,6 +1,7 @@
 use core::num::NonZero;
 
 #[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Virtual(NonZero<usize>);
 
 impl Virtual {
@@ -22,4 +23,75 @@ impl Virtual {
     pub const fn get(self) -> usize {
         self.0.get()
     }
+
+    /// Tag held in the upper bits ignored by `pmm`.
+    #[inline]
+    pub const fn tag(self, pmm: PointerMasking) -> usize {
+        match self.get().checked_shr(usize::BITS - pmm.pmlen()) {
+            Some(tag) => tag,
+            None => 0,
+        }
+    }
+
+    /// Replace the upper bits ignored by `pmm` with `tag`. Out-of-range `tag` bits are truncated.
+    #[inline]
+    pub const fn with_tag(self, pmm: PointerMasking, tag: usize) -> Option<Self> {
+        let tag = match tag.checked_shl(usize::BITS - pmm.pmlen()) {
+            Some(tag) => tag,
+            None => 0,
+        };
+        Self::new((self.get() & (usize::MAX >> pmm.pmlen())) | tag)
+    }
+
+    /// Clear the upper bits ignored by `pmm`, as for a physical address.
+    #[inline]
+    pub const fn untag(self, pmm: PointerMasking) -> Option<Self> {
+        Self::new(self.get() & (usize::MAX >> pmm.pmlen()))
+    }
+
+    /// Address the hart accesses under `pmm`: the tag is replaced by sign extension of the remaining bits.
+    #[inline]
+    pub const fn strip(self, pmm: PointerMasking) -> Option<Self> {
+        Self::new((((self.get() << pmm.pmlen()) as isize) >> pmm.pmlen()) as usize)
+    }
+}
+
+/// Number of upper address bits ignored by pointer masking.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerMasking {
+    /// Pointer masking is disabled.
+    Disabled = 0b00,
+    /// The upper 7 bits are masked.
+    Pmlen7 = 0b10,
+    /// The upper 16 bits are masked.
+    Pmlen16 = 0b11,
+}
+
+impl PointerMasking {
+    /// All masking modes.
+    pub const ALL: [Self; 3] = [Self::Disabled, Self::Pmlen7, Self::Pmlen16];
+
+    /// Number of masked bits.
+    #[inline]
+    pub const fn pmlen(self) -> u32 {
+        match self {
+            Self::Disabled => 0,
+            Self::Pmlen7 => 7,
+            Self::Pmlen16 => 16,
+        }
+    }
+}
+
+impl TryFrom<usize> for PointerMasking {
+    type Error = ();
+
+    fn try_from(pmm: usize) -> Result<Self, Self::Error> {
+        Ok(match pmm {
+            0b00 => Self::Disabled,
+            0b10 => Self::Pmlen7,
+            0b11 => Self::Pmlen16,
+            _ => return Err(()),
+        })
+    }
 }
@@ -1,6 +1,44 @@
 use core::arch::asm;
 use core::ptr::NonNull;
 
+/// Atomic swap doubleword on shadow stack memory.
+#[inline]
+#[doc(alias = "ssamoswap.d")]
+#[cfg(target_arch = "riscv64")]
+pub unsafe fn ssamoswap_d(value: u64, address: NonNull<u64>) -> u64 {
+    let old;
+    unsafe { asm!(".insn r 0x2F, 3, 0x24, {}, {}, {}", out(reg) old, in(reg) address.as_ptr(), in(reg) value, options(nostack)) };
+    old
+}
+
+/// Atomic swap doubleword on shadow stack memory, with acquire and release ordering.
+#[inline]
+#[doc(alias = "ssamoswap.d.aqrl")]
+#[cfg(target_arch = "riscv64")]
+pub unsafe fn ssamoswap_d_aqrl(value: u64, address: NonNull<u64>) -> u64 {
+    let old;
+    unsafe { asm!(".insn r 0x2F, 3, 0x27, {}, {}, {}", out(reg) old, in(reg) address.as_ptr(), in(reg) value, options(nostack)) };
+    old
+}
+
+/// Atomic swap word on shadow stack memory.
+#[inline]
+#[doc(alias = "ssamoswap.w")]
+pub unsafe fn ssamoswap_w(value: u32, address: NonNull<u32>) -> u32 {
+    let old;
+    unsafe { asm!(".insn r 0x2F, 2, 0x24, {}, {}, {}", out(reg) old, in(reg) address.as_ptr(), in(reg) value, options(nostack)) };
+    old
+}
+
+/// Atomic swap word on shadow stack memory, with acquire and release ordering.
+#[inline]
+#[doc(alias = "ssamoswap.w.aqrl")]
+pub unsafe fn ssamoswap_w_aqrl(value: u32, address: NonNull<u32>) -> u32 {
+    let old;
+    unsafe { asm!(".insn r 0x2F, 2, 0x27, {}, {}, {}", out(reg) old, in(reg) address.as_ptr(), in(reg) value, options(nostack)) };
+    old
+}
+
 /// Atomic fetch-and-add doubleword.
 #[inline]
 #[doc(alias = "amoadd.d")]
@@ -35,7 +35,20 @@ pub unsafe fn fence_i() {
 #[inline]
 #[doc(alias = "sfence.vma")]
 pub fn sfence_vma(address: usize, asid: usize) {
-    unsafe { asm!("sfence.vma {} {}", in(reg) address, in(reg) asid, options(nostack, preserves_flags)) };
+    unsafe { asm!("sfence.vma {}, {}", in(reg) address, in(reg) asid, options(nostack, preserves_flags)) };
+}
+
+/// Supervisor memory-management fence for all addresses and address spaces.
+#[inline]
+#[doc(alias = "sfence.vma")]
+pub fn sfence_vma_all() {
+    unsafe { asm!("sfence.vma", options(nostack, preserves_flags)) };
+}
+
+/// Return from a resumable non-maskable interrupt to `mnepc`, in the privilege level held in `mnstatus.MNPP`.
+#[inline]
+pub unsafe fn mnret() -> ! {
+    unsafe { asm!(".insn i 0x73, 0, x0, x0, 0x702", options(noreturn, nomem, nostack)) };
 }
 
 /// Environment call.
@@ -95,6 +108,39 @@ pub fn cbo_zero(address: NonNull<usize>) {
     unsafe { asm!("cbo.zero {}", in(reg) address.as_ptr(), options(nostack)) };
 }
 
+/// Push `value` onto the shadow stack.
+///
+/// Executes as a no-op while shadow stacks are inactive.
+#[inline]
+pub unsafe fn sspush(value: usize) {
+    unsafe { asm!(".insn r 0x73, 4, 0x67, x0, x0, t0", in("t0") value, options(nostack)) };
+}
+
+/// Pop the shadow stack, raising a software-check exception unless the popped value equals `value`.
+///
+/// Executes as a no-op while shadow stacks are inactive.
+#[inline]
+pub unsafe fn sspopchk(value: usize) {
+    unsafe { asm!(".insn i 0x73, 4, x0, t0, -804", in("t0") value, options(nostack)) };
+}
+
+/// Read the shadow stack pointer, or zero while shadow stacks are inactive.
+#[inline]
+pub fn ssrdp() -> usize {
+    let ssp;
+    unsafe { asm!(".insn i 0x73, 4, {}, x0, -804", lateout(reg) ssp, options(nomem, nostack)) };
+    ssp
+}
+
+/// Landing pad: the only valid target of an indirect jump or call while landing pads are enforced.
+///
+/// `LABEL` must match the upper 20 bits of `t2` at the jump, or be zero to accept any.
+#[inline]
+pub fn lpad<const LABEL: u32>() {
+    const { assert!(LABEL < 1 << 20, "landing pad label out of range") };
+    unsafe { asm!(".insn u 0x17, x0, {}", const LABEL, options(nomem, nostack, preserves_flags)) };
+}
+
 /// Count leading zero bits.
 ///
 /// This instruction counts the number of 0’s before the first 1, starting at the most-significant bit (i.e., XLEN-1)
@@ -8,7 +8,7 @@ where
     S: Sew,
 {
     const fn vtype(sew: u8, lmul: LMUL, ta: bool, ma: bool) -> u8 {
-        ((ma as u8) << 7) | ((ta as u8) << 6) | ((lmul as u8) << 3) | sew
+        ((ma as u8) << 7) | ((ta as u8) << 6) | (sew << 3) | lmul as u8
     }
 
     let result;
@@ -21,7 +21,7 @@ use core::marker::ConstParamTy;
 /// Vector register group multiplier.
 #[repr(u8)]
 #[non_exhaustive]
-#[derive(ConstParamTy, PartialEq, Eq)]
+#[derive(ConstParamTy, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LMUL {
     /// 1× multiplier
     M1 = 0b000,
@@ -32,11 +32,28 @@ pub enum LMUL {
     /// 8× multiplier. 
     M8 = 0b011,
     /// ½× fractional multiplier. 
-    MF2 = 0b100,
+    MF2 = 0b111,
     /// ¼× fractional multiplier.  
-    MF4 = 0b101,
+    MF4 = 0b110,
     /// ⅛× fractional multiplier.
-    MF8 = 0b110,
+    MF8 = 0b101,
+}
+
+impl TryFrom<usize> for LMUL {
+    type Error = ();
+
+    fn try_from(lmul: usize) -> Result<Self, Self::Error> {
+        Ok(match lmul {
+            0b000 => Self::M1,
+            0b001 => Self::M2,
+            0b010 => Self::M4,
+            0b011 => Self::M8,
+            0b101 => Self::MF8,
+            0b110 => Self::MF4,
+            0b111 => Self::MF2,
+            _ => return Err(()),
+        })
+    }
 }
 
 impl Into<f32> for LMUL {
@@ -69,6 +86,32 @@ impl Into<f64> for LMUL {
     }
 }
 
+/// Privilege level.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Privilege {
+    /// User mode.
+    User = 0,
+    /// Supervisor mode.
+    Supervisor = 1,
+    /// Hypervisor-extended supervisor mode.
+    Hypervisor = 2,
+    /// Machine mode.
+    Machine = 3,
+}
+
+impl From<usize> for Privilege {
+    #[inline]
+    fn from(privilege: usize) -> Self {
+        match privilege & 0b11 {
+            0 => Self::User,
+            1 => Self::Supervisor,
+            2 => Self::Hypervisor,
+            _ => Self::Machine,
+        }
+    }
+}
+
 #[repr(u8)]
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -107,3 +150,18 @@ pub enum SEW {
     /// Selected [`u128`] element width.
     E128 = 4,
 }
+
+impl TryFrom<usize> for SEW {
+    type Error = ();
+
+    fn try_from(sew: usize) -> Result<Self, Self::Error> {
+        Ok(match sew {
+            0 => Self::E8,
+            1 => Self::E16,
+            2 => Self::E32,
+            3 => Self::E64,
+            4 => Self::E128,
+            _ => return Err(()),
+        })
+    }
+}
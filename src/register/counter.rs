@@ -0,0 +1,55 @@
+use super::Csr;
+use core::marker::PhantomData;
+
+/// 64-bit counter, split across a CSR pair on RV32.
+pub trait Counter: Csr<Value = usize> + Sized {
+    /// Read the full counter, retrying on RV32 if the low half wraps between the reads of the high half.
+    #[must_use]
+    #[inline(always)]
+    fn read64() -> u64 {
+        #[cfg(target_pointer_width = "64")]
+        {
+            Self::read() as u64
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        {
+            loop {
+                let high = High::<Self>::read();
+                let low = Self::read();
+                if High::<Self>::read() == high {
+                    break ((high as u64) << 32) | (low as u64);
+                }
+            }
+        }
+    }
+
+    /// Write the full counter. On RV32 the low half is cleared first so it cannot carry into the new high half.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn write64(value: u64) {
+        #[cfg(target_pointer_width = "64")]
+        unsafe {
+            Self::write(value as usize);
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        unsafe {
+            Self::write(0);
+            High::<Self>::write((value >> 32) as usize);
+            Self::write(value as usize);
+        }
+    }
+}
+
+/// Upper 32 bits of a counter on RV32.
+#[derive(Debug, Clone, Copy)]
+pub struct High<C>(PhantomData<C>);
+
+impl<C: Counter> Csr for High<C> {
+    const ADDRESS: u16 = C::ADDRESS + 0x80;
+    type Value = usize;
+}
@@ -0,0 +1,158 @@
+use crate::Privilege;
+use core::arch::asm;
+
+/// Control and status register.
+///
+/// Implementors only declare the CSR address and the typed value; the access instructions are provided. The privilege
+/// level and read-only flag default to the ones encoded in the address (bits `9:8` and `11:10` respectively).
+pub trait Csr {
+    /// CSR address.
+    const ADDRESS: u16;
+
+    /// Lowest privilege level that can access the CSR.
+    const PRIVILEGE: Privilege = match (Self::ADDRESS >> 8) & 0b11 {
+        0 => Privilege::User,
+        1 => Privilege::Supervisor,
+        2 => Privilege::Hypervisor,
+        _ => Privilege::Machine,
+    };
+
+    /// Whether writing the CSR raises an illegal-instruction exception.
+    const READ_ONLY: bool = (Self::ADDRESS >> 10) & 0b11 == 0b11;
+
+    /// Typed value of the CSR.
+    type Value: Copy + From<usize> + Into<usize>;
+
+    /// Read the CSR.
+    #[must_use]
+    #[inline(always)]
+    #[doc(alias = "csrr")]
+    fn read() -> Self::Value {
+        let bits: usize;
+        unsafe { asm!("csrr {}, {csr}", out(reg) bits, csr = const Self::ADDRESS, options(nomem, nostack)) };
+        Self::Value::from(bits)
+    }
+
+    /// Write the CSR.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold whatever invariants the new value of the CSR imposes on the running program.
+    #[inline(always)]
+    #[doc(alias = "csrw")]
+    unsafe fn write(value: Self::Value) {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        let bits: usize = value.into();
+        unsafe { asm!("csrw {csr}, {}", in(reg) bits, csr = const Self::ADDRESS, options(nostack)) };
+    }
+
+    /// Write the CSR and return its previous value.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    #[doc(alias = "csrrw")]
+    unsafe fn swap(value: Self::Value) -> Self::Value {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        let bits: usize;
+        unsafe {
+            asm!("csrrw {}, {csr}, {}", lateout(reg) bits, in(reg) value.into(), csr = const Self::ADDRESS, options(nostack))
+        };
+        Self::Value::from(bits)
+    }
+
+    /// Set the bits of `mask` in the CSR.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    #[doc(alias = "csrs")]
+    #[doc(alias = "csrrs")]
+    unsafe fn set(mask: Self::Value) {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        let bits: usize = mask.into();
+        unsafe { asm!("csrs {csr}, {}", in(reg) bits, csr = const Self::ADDRESS, options(nostack)) };
+    }
+
+    /// Clear the bits of `mask` in the CSR.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    #[doc(alias = "csrc")]
+    #[doc(alias = "csrrc")]
+    unsafe fn clear(mask: Self::Value) {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        let bits: usize = mask.into();
+        unsafe { asm!("csrc {csr}, {}", in(reg) bits, csr = const Self::ADDRESS, options(nostack)) };
+    }
+
+    /// Set the bits of a constant `MASK` in the CSR, using `csrsi` when the mask fits in five bits.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    #[doc(alias = "csrsi")]
+    #[doc(alias = "csrrsi")]
+    unsafe fn set_const<const MASK: usize>() {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        if MASK < 32 {
+            unsafe { asm!("csrsi {csr}, {mask}", csr = const Self::ADDRESS, mask = const MASK & 0x1F, options(nostack)) };
+        } else {
+            unsafe { asm!("csrs {csr}, {}", in(reg) MASK, csr = const Self::ADDRESS, options(nostack)) };
+        }
+    }
+
+    /// Clear the bits of a constant `MASK` in the CSR, using `csrci` when the mask fits in five bits.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    #[doc(alias = "csrci")]
+    #[doc(alias = "csrrci")]
+    unsafe fn clear_const<const MASK: usize>() {
+        const { assert!(!Self::READ_ONLY, "cannot write a read-only CSR") };
+        if MASK < 32 {
+            unsafe { asm!("csrci {csr}, {mask}", csr = const Self::ADDRESS, mask = const MASK & 0x1F, options(nostack)) };
+        } else {
+            unsafe { asm!("csrc {csr}, {}", in(reg) MASK, csr = const Self::ADDRESS, options(nostack)) };
+        }
+    }
+
+    /// Read the CSR, apply `f` and write the result back.
+    ///
+    /// This is not atomic with respect to traps that modify the CSR.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn modify(f: impl FnOnce(Self::Value) -> Self::Value) {
+        unsafe { Self::write(f(Self::read())) };
+    }
+}
+
+/// Declare a [`Csr`] by address and value type, or implement it for a value type that is its own CSR.
+pub(crate) macro csr {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident: $value:ty = $address:literal;) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        $vis struct $name;
+
+        impl Csr for $name {
+            const ADDRESS: u16 = $address;
+            type Value = $value;
+        }
+    },
+    (impl $name:ty = $address:literal;) => {
+        impl Csr for $name {
+            const ADDRESS: u16 = $address;
+            type Value = Self;
+        }
+    },
+}
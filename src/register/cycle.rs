@@ -0,0 +1,14 @@
+use super::{Counter, csr};
+
+csr! {
+    /// Cycle counter for `rdcycle`.
+    pub struct Cycle: usize = 0xC00;
+}
+
+impl Counter for Cycle {}
+
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    Cycle::read64()
+}
@@ -0,0 +1,127 @@
+use super::scause::{Exception, Interrupt};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// Trap cause that can be delegated to a lower privilege level.
+pub trait Delegable: Copy + 'static + sealed::Sealed {
+    /// All causes, in ascending code order.
+    const ALL: &'static [Self];
+
+    /// Bit of the cause in the delegation register.
+    fn mask(self) -> usize;
+}
+
+impl Delegable for Exception {
+    const ALL: &'static [Self] = &Exception::ALL;
+
+    #[inline]
+    fn mask(self) -> usize {
+        Exception::mask(self)
+    }
+}
+
+impl Delegable for Interrupt {
+    const ALL: &'static [Self] = &Interrupt::ALL;
+
+    #[inline]
+    fn mask(self) -> usize {
+        Interrupt::mask(self)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for Exception {}
+impl sealed::Sealed for Interrupt {}
+
+/// Set of traps delegated to a lower privilege level.
+#[repr(transparent)]
+pub struct DelegationSet<T>(usize, PhantomData<T>);
+
+impl<T: Delegable> DelegationSet<T> {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0, PhantomData)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Add `cause` to the set.
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, cause: T) -> Self {
+        self.delegate(cause);
+        self
+    }
+
+    #[inline]
+    pub fn delegate(&mut self, cause: T) {
+        self.0 |= cause.mask();
+    }
+
+    #[inline]
+    pub fn undelegate(&mut self, cause: T) {
+        self.0 &= !cause.mask();
+    }
+
+    #[inline]
+    pub fn is_delegated(self, cause: T) -> bool {
+        self.0 & cause.mask() != 0
+    }
+
+    /// Iterate over the delegated causes.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = T> {
+        T::ALL.iter().copied().filter(move |&cause| self.is_delegated(cause))
+    }
+}
+
+impl<T: Delegable> FromIterator<T> for DelegationSet<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::empty(), Self::with)
+    }
+}
+
+impl<T> Clone for DelegationSet<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DelegationSet<T> {}
+
+impl<T> PartialEq for DelegationSet<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for DelegationSet<T> {}
+
+impl<T: Delegable + fmt::Debug> fmt::Debug for DelegationSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> From<usize> for DelegationSet<T> {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits, PhantomData)
+    }
+}
+
+impl<T> From<DelegationSet<T>> for usize {
+    #[inline]
+    fn from(set: DelegationSet<T>) -> Self {
+        set.0
+    }
+}
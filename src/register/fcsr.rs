@@ -0,0 +1,73 @@
+use super::fflags::FFlags;
+use super::frm::RoundingMode;
+use super::{Csr, csr};
+
+csr! {
+    impl Fcsr = 0x003;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Fcsr {
+    Fcsr::read()
+}
+
+#[inline]
+pub unsafe fn write(fcsr: Fcsr) {
+    unsafe { Fcsr::write(fcsr) };
+}
+
+/// Floating-point control and status register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fcsr(u32);
+
+impl Fcsr {
+    const FRM: u32 = 5;
+
+    #[inline]
+    pub const fn new(frm: RoundingMode, fflags: FFlags) -> Self {
+        Self(((frm as u32) << Self::FRM) | fflags.bits() as u32)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Dynamic rounding mode, or [`None`] for an invalid encoding.
+    #[inline]
+    pub fn frm(self) -> Option<RoundingMode> {
+        RoundingMode::try_from(((self.0 >> Self::FRM) & 0b111) as usize).ok()
+    }
+
+    #[inline]
+    pub const fn with_frm(self, frm: RoundingMode) -> Self {
+        Self((self.0 & !(0b111 << Self::FRM)) | ((frm as u32) << Self::FRM))
+    }
+
+    /// Accrued exception flags.
+    #[inline]
+    pub fn fflags(self) -> FFlags {
+        FFlags::from(self.0 as usize)
+    }
+
+    #[inline]
+    pub const fn with_fflags(self, fflags: FFlags) -> Self {
+        Self((self.0 & !0x1F) | fflags.bits() as u32)
+    }
+}
+
+impl From<usize> for Fcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u32 & 0xFF)
+    }
+}
+
+impl From<Fcsr> for usize {
+    #[inline]
+    fn from(fcsr: Fcsr) -> Self {
+        fcsr.0 as usize
+    }
+}
@@ -0,0 +1,128 @@
+use super::{Csr, csr};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+csr! {
+    impl FFlags = 0x001;
+}
+
+/// Read the accrued floating-point exception flags.
+#[inline]
+#[must_use]
+pub fn read() -> FFlags {
+    FFlags::read()
+}
+
+#[inline]
+pub fn write(fflags: FFlags) {
+    unsafe { FFlags::write(fflags) };
+}
+
+/// Clear `fflags` in the accrued exception flags.
+#[inline]
+pub fn clear(fflags: FFlags) {
+    unsafe { FFlags::clear(fflags) };
+}
+
+/// Read and clear all accrued exception flags.
+#[inline]
+pub fn take() -> FFlags {
+    unsafe { FFlags::swap(FFlags::empty()) }
+}
+
+/// Accrued IEEE 754 exception flags.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FFlags(u8);
+
+impl FFlags {
+    /// Inexact.
+    pub const NX: Self = Self(1 << 0);
+    /// Underflow.
+    pub const UF: Self = Self(1 << 1);
+    /// Overflow.
+    pub const OF: Self = Self(1 << 2);
+    /// Divide by zero.
+    pub const DZ: Self = Self(1 << 3);
+    /// Invalid operation.
+    pub const NV: Self = Self(1 << 4);
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn all() -> Self {
+        Self(0x1F)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<usize> for FFlags {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u8 & 0x1F)
+    }
+}
+
+impl From<FFlags> for usize {
+    #[inline]
+    fn from(fflags: FFlags) -> Self {
+        fflags.0 as usize
+    }
+}
+
+impl BitOr for FFlags {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FFlags {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for FFlags {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for FFlags {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Not for FFlags {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(!self.0 & 0x1F)
+    }
+}
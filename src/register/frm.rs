@@ -0,0 +1,77 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Floating-point dynamic rounding mode register.
+    pub struct Frm: usize = 0x002;
+}
+
+/// Read the dynamic rounding mode, or [`None`] if `frm` holds an invalid encoding.
+#[inline]
+#[must_use]
+pub fn read() -> Option<RoundingMode> {
+    RoundingMode::try_from(Frm::read()).ok()
+}
+
+/// Set the dynamic rounding mode.
+///
+/// The compiler assumes round-to-nearest-even; prefer [`RoundingGuard`] to keep the change scoped.
+#[inline]
+pub unsafe fn write(mode: RoundingMode) {
+    unsafe { Frm::write(mode as usize) };
+}
+
+/// IEEE 754 rounding mode held in `frm`.
+///
+/// The dynamic encoding `0b111` is only meaningful in an instruction's rounding-mode field and is illegal in `frm`, so
+/// it has no variant here.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even.
+    RNE = 0b000,
+    /// Round towards zero.
+    RTZ = 0b001,
+    /// Round down, towards negative infinity.
+    RDN = 0b010,
+    /// Round up, towards positive infinity.
+    RUP = 0b011,
+    /// Round to nearest, ties to max magnitude.
+    RMM = 0b100,
+}
+
+impl TryFrom<usize> for RoundingMode {
+    type Error = ();
+
+    fn try_from(mode: usize) -> Result<Self, Self::Error> {
+        Ok(match mode {
+            0b000 => Self::RNE,
+            0b001 => Self::RTZ,
+            0b010 => Self::RDN,
+            0b011 => Self::RUP,
+            0b100 => Self::RMM,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Switches the dynamic rounding mode for its lifetime and restores the previous one when dropped.
+#[derive(Debug)]
+#[must_use = "the previous rounding mode is restored as soon as the guard is dropped"]
+pub struct RoundingGuard {
+    previous: usize,
+}
+
+impl RoundingGuard {
+    /// Set the dynamic rounding mode to `mode`.
+    #[inline]
+    pub unsafe fn new(mode: RoundingMode) -> Self {
+        Self { previous: unsafe { Frm::swap(mode as usize) } }
+    }
+}
+
+impl Drop for RoundingGuard {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { Frm::write(self.previous) };
+    }
+}
@@ -0,0 +1,18 @@
+use super::scounteren::Counteren;
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor counter-enable register.
+    pub struct Hcounteren: Counteren = 0x606;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Counteren {
+    Hcounteren::read()
+}
+
+#[inline]
+pub unsafe fn write(hcounteren: Counteren) {
+    unsafe { Hcounteren::write(hcounteren) };
+}
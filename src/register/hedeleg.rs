@@ -0,0 +1,30 @@
+use super::scause::Exception;
+use super::{Csr, DelegationSet, csr};
+
+csr! {
+    /// Hypervisor exception delegation register.
+    pub struct Hedeleg: DelegationSet<Exception> = 0x602;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> DelegationSet<Exception> {
+    Hedeleg::read()
+}
+
+#[inline]
+pub unsafe fn write(hedeleg: DelegationSet<Exception>) {
+    unsafe { Hedeleg::write(hedeleg) };
+}
+
+/// Delegate an exception raised in a guest to VS-mode.
+#[inline]
+pub unsafe fn delegate(exception: Exception) {
+    unsafe { Hedeleg::set(DelegationSet::empty().with(exception)) };
+}
+
+/// Handle an exception raised in a guest in HS-mode again.
+#[inline]
+pub unsafe fn undelegate(exception: Exception) {
+    unsafe { Hedeleg::clear(DelegationSet::empty().with(exception)) };
+}
@@ -0,0 +1,259 @@
+use super::menvcfg::CacheBlockInvalidate;
+use super::{Csr, csr};
+#[cfg(target_pointer_width = "64")]
+use crate::PointerMasking;
+
+csr! {
+    /// Hypervisor environment configuration register, or its lower half on RV32.
+    struct Low: usize = 0x60A;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    /// Upper 32 bits of the hypervisor environment configuration register on RV32.
+    struct High: usize = 0x61A;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Henvcfg {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Henvcfg(Low::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Henvcfg(((High::read() as u64) << 32) | (Low::read() as u64))
+    }
+}
+
+#[inline]
+pub unsafe fn write(henvcfg: Henvcfg) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::write(henvcfg.0 as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Low::write(henvcfg.0 as usize);
+        High::write((henvcfg.0 >> 32) as usize);
+    }
+}
+
+#[inline]
+pub unsafe fn update(f: impl FnOnce(Henvcfg) -> Henvcfg) {
+    unsafe { write(f(read())) };
+}
+
+/// Let the guest program its own timer through `vstimecmp`.
+#[inline]
+pub unsafe fn set_stce() {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::set_const::<{ Henvcfg::STCE as usize }>();
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        High::set_const::<{ (Henvcfg::STCE >> 32) as usize }>();
+    }
+}
+
+#[inline]
+pub unsafe fn clear_stce() {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::clear_const::<{ Henvcfg::STCE as usize }>();
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        High::clear_const::<{ (Henvcfg::STCE >> 32) as usize }>();
+    }
+}
+
+/// Check whether the hart implements pointer masking `pmm` for VS-mode.
+///
+/// The field is WARL: an unsupported length reads back differently. The previous value is restored afterwards.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+pub unsafe fn probe(pmm: PointerMasking) -> bool {
+    unsafe {
+        let henvcfg = read();
+        let mut probe = henvcfg;
+        probe.set_pmm(pmm);
+        write(probe);
+        let supported = read().pmm() == Some(pmm);
+        write(henvcfg);
+        supported
+    }
+}
+
+/// Hypervisor environment configuration register. Controls the execution environment of VS-mode.
+///
+/// Fields left disabled by `menvcfg` are read-only zero.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Henvcfg(u64);
+
+impl Henvcfg {
+    const FIOM: u64 = 1 << 0;
+    const LPE: u64 = 1 << 2;
+    const SSE: u64 = 1 << 3;
+    const CBIE: u64 = 4;
+    const CBCFE: u64 = 1 << 6;
+    const CBZE: u64 = 1 << 7;
+    #[cfg(target_pointer_width = "64")]
+    const PMM: u64 = 32;
+    const ADUE: u64 = 1 << 61;
+    const PBMTE: u64 = 1 << 62;
+    const STCE: u64 = 1 << 63;
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: u64) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: u64, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Fence of I/O implies memory: `fence` on I/O in VS-mode and VU-mode also orders memory accesses.
+    #[inline]
+    pub const fn fiom(self) -> bool {
+        self.bit(Self::FIOM)
+    }
+
+    #[inline]
+    pub const fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(Self::FIOM, fiom);
+    }
+
+    /// Landing pads are enforced in VS-mode.
+    #[inline]
+    pub const fn lpe(self) -> bool {
+        self.bit(Self::LPE)
+    }
+
+    #[inline]
+    pub const fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(Self::LPE, lpe);
+    }
+
+    /// Shadow stacks are active in VS-mode.
+    #[inline]
+    pub const fn sse(self) -> bool {
+        self.bit(Self::SSE)
+    }
+
+    #[inline]
+    pub const fn set_sse(&mut self, sse: bool) {
+        self.set_bit(Self::SSE, sse);
+    }
+
+    /// Behaviour of `cbo.inval` in VS-mode and VU-mode, or [`None`] for the reserved encoding.
+    #[inline]
+    pub fn cbie(self) -> Option<CacheBlockInvalidate> {
+        CacheBlockInvalidate::try_from(((self.0 >> Self::CBIE) & 0b11) as usize).ok()
+    }
+
+    #[inline]
+    pub const fn set_cbie(&mut self, cbie: CacheBlockInvalidate) {
+        self.0 = (self.0 & !(0b11 << Self::CBIE)) | ((cbie as u64) << Self::CBIE);
+    }
+
+    /// `cbo.clean` and `cbo.flush` are allowed in VS-mode and VU-mode.
+    #[inline]
+    pub const fn cbcfe(self) -> bool {
+        self.bit(Self::CBCFE)
+    }
+
+    #[inline]
+    pub const fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(Self::CBCFE, cbcfe);
+    }
+
+    /// `cbo.zero` is allowed in VS-mode and VU-mode.
+    #[inline]
+    pub const fn cbze(self) -> bool {
+        self.bit(Self::CBZE)
+    }
+
+    #[inline]
+    pub const fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(Self::CBZE, cbze);
+    }
+
+    /// Pointer masking for VS-mode, or [`None`] for the reserved encoding.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn pmm(self) -> Option<PointerMasking> {
+        PointerMasking::try_from(((self.0 >> Self::PMM) & 0b11) as usize).ok()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_pmm(&mut self, pmm: PointerMasking) {
+        self.0 = (self.0 & !(0b11 << Self::PMM)) | ((pmm as u64) << Self::PMM);
+    }
+
+    /// Hardware updates the A and D bits of VS-stage page table entries.
+    #[inline]
+    pub const fn adue(self) -> bool {
+        self.bit(Self::ADUE)
+    }
+
+    #[inline]
+    pub const fn set_adue(&mut self, adue: bool) {
+        self.set_bit(Self::ADUE, adue);
+    }
+
+    /// Svpbmt page-based memory types are honoured in VS-stage page tables.
+    #[inline]
+    pub const fn pbmte(self) -> bool {
+        self.bit(Self::PBMTE)
+    }
+
+    #[inline]
+    pub const fn set_pbmte(&mut self, pbmte: bool) {
+        self.set_bit(Self::PBMTE, pbmte);
+    }
+
+    /// Virtual supervisor timer compare: `vstimecmp` is accessible and drives the VS-level timer interrupt.
+    #[inline]
+    pub const fn stce(self) -> bool {
+        self.bit(Self::STCE)
+    }
+
+    #[inline]
+    pub const fn set_stce(&mut self, stce: bool) {
+        self.set_bit(Self::STCE, stce);
+    }
+}
+
+impl From<u64> for Henvcfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Henvcfg> for u64 {
+    #[inline]
+    fn from(henvcfg: Henvcfg) -> Self {
+        henvcfg.0
+    }
+}
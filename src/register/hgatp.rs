@@ -0,0 +1,155 @@
+use super::{Csr, csr};
+
+csr! {
+    impl Hgatp = 0x680;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Hgatp {
+    Hgatp::read()
+}
+
+#[inline]
+pub unsafe fn write(hgatp: Hgatp) {
+    unsafe { Hgatp::write(hgatp) };
+}
+
+/// Discover how many VMID bits the hart implements.
+///
+/// Temporarily writes all ones to the VMID field. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn vmid_bits() -> u32 {
+    unsafe {
+        let hgatp = read();
+        let mut probe = hgatp;
+        probe.set_vmid(usize::MAX);
+        write(probe);
+        let bits = read().vmid().count_ones();
+        write(hgatp);
+        bits
+    }
+}
+
+/// Hypervisor guest address translation and protection register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hgatp(usize);
+
+impl Hgatp {
+    #[cfg(target_pointer_width = "32")]
+    const MODE: usize = 31;
+    #[cfg(target_pointer_width = "32")]
+    const VMID: usize = 22;
+    #[cfg(target_pointer_width = "32")]
+    const VMID_MASK: usize = 0x7F;
+    #[cfg(target_pointer_width = "32")]
+    const PPN_MASK: usize = 0x3F_FFFF;
+
+    #[cfg(target_pointer_width = "64")]
+    const MODE: usize = 60;
+    #[cfg(target_pointer_width = "64")]
+    const VMID: usize = 44;
+    #[cfg(target_pointer_width = "64")]
+    const VMID_MASK: usize = 0x3FFF;
+    #[cfg(target_pointer_width = "64")]
+    const PPN_MASK: usize = 0xFFF_FFFF_FFFF;
+
+    /// Build an `hgatp` value. The root page table is 16 KiB, so the two low bits of `ppn` must be zero.
+    #[inline]
+    pub const fn new(mode: Mode, vmid: usize, ppn: usize) -> Self {
+        Self(((mode as usize) << Self::MODE) | ((vmid & Self::VMID_MASK) << Self::VMID) | (ppn & Self::PPN_MASK))
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Guest physical address translation scheme, or [`None`] for a reserved encoding.
+    #[inline]
+    pub fn mode(self) -> Option<Mode> {
+        Mode::try_from(self.0 >> Self::MODE).ok()
+    }
+
+    #[inline]
+    pub const fn set_mode(&mut self, mode: Mode) {
+        self.0 = (self.0 & ((1 << Self::MODE) - 1)) | ((mode as usize) << Self::MODE);
+    }
+
+    /// Virtual machine identifier.
+    #[inline]
+    pub const fn vmid(self) -> usize {
+        (self.0 >> Self::VMID) & Self::VMID_MASK
+    }
+
+    #[inline]
+    pub const fn set_vmid(&mut self, vmid: usize) {
+        self.0 = (self.0 & !(Self::VMID_MASK << Self::VMID)) | ((vmid & Self::VMID_MASK) << Self::VMID);
+    }
+
+    /// Physical page number of the root guest page table.
+    #[inline]
+    pub const fn ppn(self) -> usize {
+        self.0 & Self::PPN_MASK
+    }
+
+    #[inline]
+    pub const fn set_ppn(&mut self, ppn: usize) {
+        self.0 = (self.0 & !Self::PPN_MASK) | (ppn & Self::PPN_MASK);
+    }
+}
+
+impl From<usize> for Hgatp {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Hgatp> for usize {
+    #[inline]
+    fn from(hgatp: Hgatp) -> Self {
+        hgatp.0
+    }
+}
+
+/// Guest physical address translation scheme.
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// No translation or protection.
+    Bare = 0,
+    /// Page-based 34-bit guest physical addressing.
+    #[cfg(target_pointer_width = "32")]
+    Sv32x4 = 1,
+    /// Page-based 41-bit guest physical addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv39x4 = 8,
+    /// Page-based 50-bit guest physical addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv48x4 = 9,
+    /// Page-based 59-bit guest physical addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv57x4 = 10,
+}
+
+impl TryFrom<usize> for Mode {
+    type Error = ();
+
+    fn try_from(mode: usize) -> Result<Self, Self::Error> {
+        Ok(match mode {
+            0 => Self::Bare,
+            #[cfg(target_pointer_width = "32")]
+            1 => Self::Sv32x4,
+            #[cfg(target_pointer_width = "64")]
+            8 => Self::Sv39x4,
+            #[cfg(target_pointer_width = "64")]
+            9 => Self::Sv48x4,
+            #[cfg(target_pointer_width = "64")]
+            10 => Self::Sv57x4,
+            _ => return Err(()),
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use super::hgeip::GuestExternal;
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor guest external interrupt-enable register.
+    pub struct Hgeie: GuestExternal = 0x607;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> GuestExternal {
+    Hgeie::read()
+}
+
+#[inline]
+pub unsafe fn write(hgeie: GuestExternal) {
+    unsafe { Hgeie::write(hgeie) };
+}
+
+/// Let guest external interrupt `n` raise a supervisor guest external interrupt.
+#[inline]
+pub unsafe fn enable(n: usize) {
+    unsafe { Hgeie::set(GuestExternal::empty().with(n, true)) };
+}
+
+#[inline]
+pub unsafe fn disable(n: usize) {
+    unsafe { Hgeie::clear(GuestExternal::empty().with(n, true)) };
+}
+
+/// Discover GEILEN, the number of guest external interrupts.
+///
+/// Implemented bits are contiguous from bit 1, so writing all ones reads back GEILEN set bits. The previous value is
+/// restored afterwards.
+#[inline]
+pub unsafe fn geilen() -> usize {
+    unsafe {
+        let hgeie = Hgeie::swap(GuestExternal::from(usize::MAX));
+        let geilen = read().bits().count_ones() as usize;
+        write(hgeie);
+        geilen
+    }
+}
@@ -0,0 +1,67 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor guest external interrupt-pending register.
+    pub struct Hgeip: GuestExternal = 0xE12;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> GuestExternal {
+    Hgeip::read()
+}
+
+/// Set of guest external interrupts, numbered from 1 to GEILEN. Each one belongs to a guest interrupt file.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuestExternal(usize);
+
+impl GuestExternal {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Guest external interrupt `n` is in the set.
+    #[inline]
+    pub const fn get(self, n: usize) -> bool {
+        assert!(n >= 1 && n < usize::BITS as usize, "guest external interrupt out of range");
+        self.0 & (1 << n) != 0
+    }
+
+    #[inline]
+    pub const fn with(self, n: usize, value: bool) -> Self {
+        assert!(n >= 1 && n < usize::BITS as usize, "guest external interrupt out of range");
+        if value { Self(self.0 | (1 << n)) } else { Self(self.0 & !(1 << n)) }
+    }
+
+    /// Iterate over the guest external interrupts in the set, lowest first.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = usize> {
+        (1..usize::BITS as usize).filter(move |&n| self.0 & (1 << n) != 0)
+    }
+}
+
+impl From<usize> for GuestExternal {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<GuestExternal> for usize {
+    #[inline]
+    fn from(guest: GuestExternal) -> Self {
+        guest.0
+    }
+}
@@ -0,0 +1,30 @@
+use super::scause::Interrupt;
+use super::{Csr, DelegationSet, csr};
+
+csr! {
+    /// Hypervisor interrupt delegation register.
+    pub struct Hideleg: DelegationSet<Interrupt> = 0x603;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> DelegationSet<Interrupt> {
+    Hideleg::read()
+}
+
+#[inline]
+pub unsafe fn write(hideleg: DelegationSet<Interrupt>) {
+    unsafe { Hideleg::write(hideleg) };
+}
+
+/// Delegate a VS-level interrupt to VS-mode.
+#[inline]
+pub unsafe fn delegate(interrupt: Interrupt) {
+    unsafe { Hideleg::set(DelegationSet::empty().with(interrupt)) };
+}
+
+/// Handle a VS-level interrupt in HS-mode again.
+#[inline]
+pub unsafe fn undelegate(interrupt: Interrupt) {
+    unsafe { Hideleg::clear(DelegationSet::empty().with(interrupt)) };
+}
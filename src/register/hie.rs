@@ -0,0 +1,94 @@
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    impl Hie = 0x604;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Hie {
+    Hie::read()
+}
+
+#[inline]
+pub unsafe fn write(hie: Hie) {
+    unsafe { Hie::write(hie) };
+}
+
+/// Enable a VS-level or guest external interrupt source. Only the bits of [`Hie::MASK`] are writable.
+#[inline]
+pub unsafe fn enable(interrupt: Interrupt) {
+    unsafe { Hie::set(Hie(interrupt.mask())) };
+}
+
+/// Disable a VS-level or guest external interrupt source.
+#[inline]
+pub unsafe fn disable(interrupt: Interrupt) {
+    unsafe { Hie::clear(Hie(interrupt.mask())) };
+}
+
+/// Hypervisor interrupt-enable register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hie(usize);
+
+impl Hie {
+    /// Interrupts controlled by `hie`.
+    pub const MASK: usize = Interrupt::VirtualSupervisorSoftware.mask()
+        | Interrupt::VirtualSupervisorTimer.mask()
+        | Interrupt::VirtualSupervisorExternal.mask()
+        | Interrupt::SupervisorGuestExternal.mask();
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_enabled(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    #[inline]
+    pub const fn enable(&mut self, interrupt: Interrupt) {
+        self.0 |= interrupt.mask();
+    }
+
+    #[inline]
+    pub const fn disable(&mut self, interrupt: Interrupt) {
+        self.0 &= !interrupt.mask();
+    }
+
+    /// Iterate over the enabled interrupt sources.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.into_iter().filter(move |&interrupt| self.is_enabled(interrupt))
+    }
+}
+
+impl FromIterator<Interrupt> for Hie {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Interrupt>>(iter: I) -> Self {
+        Self(iter.into_iter().fold(0, |bits, interrupt| bits | interrupt.mask()))
+    }
+}
+
+impl From<usize> for Hie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Hie> for usize {
+    #[inline]
+    fn from(hie: Hie) -> Self {
+        hie.0
+    }
+}
@@ -0,0 +1,57 @@
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    impl Hip = 0x644;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Hip {
+    Hip::read()
+}
+
+#[inline]
+#[must_use]
+pub fn is_pending(interrupt: Interrupt) -> bool {
+    read().is_pending(interrupt)
+}
+
+/// Hypervisor interrupt-pending register.
+///
+/// VS-level pending bits reflect `hvip` combined with platform sources; inject interrupts through `hvip` instead.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hip(usize);
+
+impl Hip {
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_pending(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    /// Iterate over the pending interrupt sources, lowest code first.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.into_iter().filter(move |&interrupt| self.is_pending(interrupt))
+    }
+}
+
+impl From<usize> for Hip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Hip> for usize {
+    #[inline]
+    fn from(hip: Hip) -> Self {
+        hip.0
+    }
+}
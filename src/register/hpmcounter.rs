@@ -0,0 +1,21 @@
+use super::{Counter, Csr};
+
+/// Hardware performance-monitoring counter `hpmcounterN`, a read-only shadow of `mhpmcounterN`.
+#[derive(Debug, Clone, Copy)]
+pub struct Hpmcounter<const N: usize>;
+
+impl<const N: usize> Csr for Hpmcounter<N> {
+    const ADDRESS: u16 = {
+        assert!(N >= 3 && N <= 31, "hpmcounter index out of range");
+        0xC00 + N as u16
+    };
+    type Value = usize;
+}
+
+impl<const N: usize> Counter for Hpmcounter<N> {}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> u64 {
+    Hpmcounter::<N>::read64()
+}
@@ -0,0 +1,70 @@
+use super::{Csr, Stateen};
+
+/// Hypervisor state-enable register `hstateenN`, or its lower half on RV32.
+#[derive(Debug, Clone, Copy)]
+pub struct Hstateen<const N: usize>;
+
+impl<const N: usize> Csr for Hstateen<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 4, "hstateen index out of range");
+        0x60C + N as u16
+    };
+    type Value = usize;
+}
+
+/// Upper 32 bits of the hypervisor state-enable register `hstateenN` on RV32.
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_pointer_width = "32")]
+pub struct Hstateenh<const N: usize>;
+
+#[cfg(target_pointer_width = "32")]
+impl<const N: usize> Csr for Hstateenh<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 4, "hstateen index out of range");
+        0x61C + N as u16
+    };
+    type Value = usize;
+}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> Stateen {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Stateen::from_bits(Hstateen::<N>::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Stateen::from_bits(((Hstateenh::<N>::read() as u64) << 32) | (Hstateen::<N>::read() as u64))
+    }
+}
+
+/// Grant VS-mode access to the state guarded by the bits set in `stateen`, and deny it everything else.
+#[inline]
+pub unsafe fn write<const N: usize>(stateen: Stateen) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Hstateen::<N>::write(stateen.bits() as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Hstateen::<N>::write(stateen.bits() as usize);
+        Hstateenh::<N>::write((stateen.bits() >> 32) as usize);
+    }
+}
+
+/// Discover which bits of `hstateenN` the hart implements.
+///
+/// All bits are WARL, so writing all ones reads back as the implemented set. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn implemented<const N: usize>() -> Stateen {
+    unsafe {
+        let stateen = read::<N>();
+        write::<N>(Stateen::all());
+        let implemented = read::<N>();
+        write::<N>(stateen);
+        implemented
+    }
+}
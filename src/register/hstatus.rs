@@ -0,0 +1,198 @@
+use super::{Csr, csr};
+#[cfg(target_pointer_width = "64")]
+use crate::{PointerMasking, XLEN};
+
+csr! {
+    impl Hstatus = 0x600;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Hstatus {
+    Hstatus::read()
+}
+
+#[inline]
+pub unsafe fn write(hstatus: Hstatus) {
+    unsafe { Hstatus::write(hstatus) };
+}
+
+/// Hypervisor status register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hstatus(usize);
+
+impl Hstatus {
+    const VSBE: usize = 1 << 5;
+    const GVA: usize = 1 << 6;
+    const SPV: usize = 1 << 7;
+    const SPVP: usize = 1 << 8;
+    const HU: usize = 1 << 9;
+    const VGEIN: usize = 12;
+    const VTVM: usize = 1 << 20;
+    const VTW: usize = 1 << 21;
+    const VTSR: usize = 1 << 22;
+    #[cfg(target_pointer_width = "64")]
+    const VSXL: usize = 32;
+    #[cfg(target_pointer_width = "64")]
+    const HUPMM: usize = 48;
+
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: usize) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: usize, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// VS-mode memory accesses are big-endian.
+    #[inline]
+    pub const fn vsbe(self) -> bool {
+        self.bit(Self::VSBE)
+    }
+
+    #[inline]
+    pub const fn set_vsbe(&mut self, vsbe: bool) {
+        self.set_bit(Self::VSBE, vsbe);
+    }
+
+    /// `stval` holds a guest virtual address.
+    #[inline]
+    pub const fn gva(self) -> bool {
+        self.bit(Self::GVA)
+    }
+
+    #[inline]
+    pub const fn set_gva(&mut self, gva: bool) {
+        self.set_bit(Self::GVA, gva);
+    }
+
+    /// Supervisor previous virtualization mode: the trap was taken from a guest.
+    #[inline]
+    pub const fn spv(self) -> bool {
+        self.bit(Self::SPV)
+    }
+
+    #[inline]
+    pub const fn set_spv(&mut self, spv: bool) {
+        self.set_bit(Self::SPV, spv);
+    }
+
+    /// Supervisor previous virtual privilege: the guest was in VS-mode rather than VU-mode.
+    #[inline]
+    pub const fn spvp(self) -> bool {
+        self.bit(Self::SPVP)
+    }
+
+    #[inline]
+    pub const fn set_spvp(&mut self, spvp: bool) {
+        self.set_bit(Self::SPVP, spvp);
+    }
+
+    /// Hypervisor load/store instructions are allowed in U-mode.
+    #[inline]
+    pub const fn hu(self) -> bool {
+        self.bit(Self::HU)
+    }
+
+    #[inline]
+    pub const fn set_hu(&mut self, hu: bool) {
+        self.set_bit(Self::HU, hu);
+    }
+
+    /// Guest external interrupt number routed to VS-level external interrupts.
+    #[inline]
+    pub const fn vgein(self) -> usize {
+        (self.0 >> Self::VGEIN) & 0x3F
+    }
+
+    #[inline]
+    pub const fn set_vgein(&mut self, vgein: usize) {
+        self.0 = (self.0 & !(0x3F << Self::VGEIN)) | ((vgein & 0x3F) << Self::VGEIN);
+    }
+
+    /// Trap virtual memory: `satp` accesses and `sfence.vma` in VS-mode are virtual instructions.
+    #[inline]
+    pub const fn vtvm(self) -> bool {
+        self.bit(Self::VTVM)
+    }
+
+    #[inline]
+    pub const fn set_vtvm(&mut self, vtvm: bool) {
+        self.set_bit(Self::VTVM, vtvm);
+    }
+
+    /// Timeout wait: `wfi` in VS-mode is a virtual instruction unless it completes quickly.
+    #[inline]
+    pub const fn vtw(self) -> bool {
+        self.bit(Self::VTW)
+    }
+
+    #[inline]
+    pub const fn set_vtw(&mut self, vtw: bool) {
+        self.set_bit(Self::VTW, vtw);
+    }
+
+    /// Trap `sret` in VS-mode.
+    #[inline]
+    pub const fn vtsr(self) -> bool {
+        self.bit(Self::VTSR)
+    }
+
+    #[inline]
+    pub const fn set_vtsr(&mut self, vtsr: bool) {
+        self.set_bit(Self::VTSR, vtsr);
+    }
+
+    /// Effective XLEN of VS-mode.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn vsxl(self) -> XLEN {
+        XLEN::try_from((self.0 >> Self::VSXL) & 0b11).unwrap_or_default()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_vsxl(&mut self, vsxl: XLEN) {
+        self.0 = (self.0 & !(0b11 << Self::VSXL)) | ((vsxl as usize) << Self::VSXL);
+    }
+
+    /// Pointer masking applied to `hlv` and `hsv` accesses made as VU-mode, or [`None`] for the reserved encoding.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn hupmm(self) -> Option<PointerMasking> {
+        PointerMasking::try_from((self.0 >> Self::HUPMM) & 0b11).ok()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_hupmm(&mut self, hupmm: PointerMasking) {
+        self.0 = (self.0 & !(0b11 << Self::HUPMM)) | ((hupmm as usize) << Self::HUPMM);
+    }
+}
+
+impl From<usize> for Hstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Hstatus> for usize {
+    #[inline]
+    fn from(hstatus: Hstatus) -> Self {
+        hstatus.0
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor time delta register.
+    pub struct Htimedelta: usize = 0x605;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    /// Upper 32 bits of the hypervisor time delta register on RV32.
+    pub struct Htimedeltah: usize = 0x615;
+}
+
+/// Read the offset added to `time` when read from a guest.
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Htimedelta::read() as u64
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        ((Htimedeltah::read() as u64) << 32) | (Htimedelta::read() as u64)
+    }
+}
+
+#[inline]
+pub unsafe fn write(htimedelta: u64) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Htimedelta::write(htimedelta as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Htimedelta::write(htimedelta as usize);
+        Htimedeltah::write((htimedelta >> 32) as usize);
+    }
+}
@@ -0,0 +1,13 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor trap instruction register.
+    pub struct Htinst: usize = 0x64A;
+}
+
+/// Read the transformed trapping instruction, or `0` if not provided.
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Htinst::read()
+}
@@ -0,0 +1,13 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Hypervisor trap value register.
+    pub struct Htval: usize = 0x643;
+}
+
+/// Read the faulting guest physical address of a guest-page fault, or `0`.
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    (Htval::read() as u64) << 2
+}
@@ -0,0 +1,81 @@
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    impl Hvip = 0x645;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Hvip {
+    Hvip::read()
+}
+
+#[inline]
+pub unsafe fn write(hvip: Hvip) {
+    unsafe { Hvip::write(hvip) };
+}
+
+/// Inject a VS-level interrupt into the guest.
+#[inline]
+pub unsafe fn inject(interrupt: Interrupt) {
+    unsafe { Hvip::set(Hvip(interrupt.mask())) };
+}
+
+/// Withdraw an injected VS-level interrupt.
+#[inline]
+pub unsafe fn withdraw(interrupt: Interrupt) {
+    unsafe { Hvip::clear(Hvip(interrupt.mask())) };
+}
+
+/// Hypervisor virtual interrupt-pending register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hvip(usize);
+
+impl Hvip {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_pending(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    #[inline]
+    pub const fn inject(&mut self, interrupt: Interrupt) {
+        self.0 |= interrupt.mask();
+    }
+
+    #[inline]
+    pub const fn withdraw(&mut self, interrupt: Interrupt) {
+        self.0 &= !interrupt.mask();
+    }
+
+    /// Iterate over the injected interrupts.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.into_iter().filter(move |&interrupt| self.is_pending(interrupt))
+    }
+}
+
+impl From<usize> for Hvip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Hvip> for usize {
+    #[inline]
+    fn from(hvip: Hvip) -> Self {
+        hvip.0
+    }
+}
@@ -0,0 +1,175 @@
+use super::miselect::Miselect;
+use super::mtopei::Mtopei;
+use super::siselect::Siselect;
+use super::stopei::Stopei;
+use super::{Csr, Indirect};
+use core::num::NonZero;
+
+const EIDELIVERY: usize = 0x70;
+const EITHRESHOLD: usize = 0x72;
+const EIP0: usize = 0x80;
+const EIE0: usize = 0xC0;
+
+/// Select number of the `eip` or `eie` register holding the bit of `identity`, and the mask of that bit.
+///
+/// On RV64 only the even-numbered registers exist, each covering 64 identities.
+#[inline]
+const fn locate(base: usize, identity: Identity) -> (usize, usize) {
+    let identity = identity.get() as usize;
+    let index = identity / usize::BITS as usize;
+    (base + index * (usize::BITS as usize / 32), 1 << (identity % usize::BITS as usize))
+}
+
+/// Interrupt file of an incoming MSI controller, accessed through the indirect CSR window of its privilege level.
+///
+/// Every method writes the select CSR, so none may be interleaved with another indirect access on the same hart.
+pub trait InterruptFile: sealed::Sealed {
+    type Select: Indirect;
+    type Topei: Csr<Value = usize>;
+
+    /// How external interrupts reach the hart, or [`None`] for a reserved value.
+    #[inline]
+    unsafe fn delivery() -> Option<Delivery> {
+        unsafe { Delivery::try_from(Self::Select::read_indirect(EIDELIVERY)).ok() }
+    }
+
+    #[inline]
+    unsafe fn set_delivery(delivery: Delivery) {
+        unsafe { Self::Select::write_indirect(EIDELIVERY, delivery as usize) };
+    }
+
+    /// Lowest identity that is masked, or [`None`] if no identity is masked by threshold.
+    #[inline]
+    unsafe fn threshold() -> Option<Identity> {
+        unsafe { Identity::new(Self::Select::read_indirect(EITHRESHOLD) as u16) }
+    }
+
+    /// Mask every identity equal to or above `threshold`. [`None`] unmasks all of them.
+    #[inline]
+    unsafe fn set_threshold(threshold: Option<Identity>) {
+        unsafe { Self::Select::write_indirect(EITHRESHOLD, threshold.map_or(0, |threshold| threshold.get() as usize)) };
+    }
+
+    #[inline]
+    unsafe fn is_pending(identity: Identity) -> bool {
+        let (select, mask) = locate(EIP0, identity);
+        unsafe { Self::Select::read_indirect(select) & mask != 0 }
+    }
+
+    /// Raise `identity` locally, as though an MSI carrying it had arrived.
+    #[inline]
+    unsafe fn set_pending(identity: Identity) {
+        let (select, mask) = locate(EIP0, identity);
+        unsafe { Self::Select::set_indirect(select, mask) };
+    }
+
+    #[inline]
+    unsafe fn clear_pending(identity: Identity) {
+        let (select, mask) = locate(EIP0, identity);
+        unsafe { Self::Select::clear_indirect(select, mask) };
+    }
+
+    #[inline]
+    unsafe fn is_enabled(identity: Identity) -> bool {
+        let (select, mask) = locate(EIE0, identity);
+        unsafe { Self::Select::read_indirect(select) & mask != 0 }
+    }
+
+    #[inline]
+    unsafe fn enable(identity: Identity) {
+        let (select, mask) = locate(EIE0, identity);
+        unsafe { Self::Select::set_indirect(select, mask) };
+    }
+
+    #[inline]
+    unsafe fn disable(identity: Identity) {
+        let (select, mask) = locate(EIE0, identity);
+        unsafe { Self::Select::clear_indirect(select, mask) };
+    }
+
+    /// Highest-priority pending and enabled identity, without claiming it.
+    #[inline]
+    fn top() -> Option<Identity> {
+        Identity::new((Self::Topei::read() >> 16) as u16)
+    }
+
+    /// Claim the highest-priority pending and enabled identity, clearing its pending bit.
+    #[inline]
+    unsafe fn claim() -> Option<Identity> {
+        Identity::new((unsafe { Self::Topei::swap(0) } >> 16) as u16)
+    }
+}
+
+/// Machine-level interrupt file, accessed through `miselect`, `mireg` and `mtopei`.
+#[derive(Debug, Clone, Copy)]
+pub struct Machine;
+
+impl InterruptFile for Machine {
+    type Select = Miselect;
+    type Topei = Mtopei;
+}
+
+/// Supervisor-level interrupt file, accessed through `siselect`, `sireg` and `stopei`.
+#[derive(Debug, Clone, Copy)]
+pub struct Supervisor;
+
+impl InterruptFile for Supervisor {
+    type Select = Siselect;
+    type Topei = Stopei;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for Machine {}
+impl sealed::Sealed for Supervisor {}
+
+/// Delivery mode of an interrupt file.
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// No external interrupts are delivered to the hart.
+    Disabled = 0,
+    /// Interrupts are delivered from the interrupt file.
+    Imsic = 1,
+    /// Interrupts are delivered from a PLIC or APLIC in direct mode, bypassing the interrupt file.
+    Direct = 0x4000_0000,
+}
+
+impl TryFrom<usize> for Delivery {
+    type Error = ();
+
+    fn try_from(delivery: usize) -> Result<Self, Self::Error> {
+        Ok(match delivery {
+            0 => Self::Disabled,
+            1 => Self::Imsic,
+            0x4000_0000 => Self::Direct,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// External interrupt identity, the data word of an MSI. Also its priority: lower identities take precedence.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Identity(NonZero<u16>);
+
+impl Identity {
+    /// Largest identity an interrupt file may implement.
+    pub const MAX: u16 = 2047;
+
+    /// Identity `identity`, or [`None`] if it is zero or above [`Identity::MAX`].
+    #[inline]
+    pub const fn new(identity: u16) -> Option<Self> {
+        match NonZero::new(identity) {
+            Some(identity) if identity.get() <= Self::MAX => Some(Self(identity)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub const fn get(self) -> u16 {
+        self.0.get()
+    }
+}
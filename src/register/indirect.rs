@@ -0,0 +1,60 @@
+use super::Csr;
+
+/// Select CSR of an indirect CSR access window, such as `siselect` paired with `sireg`.
+///
+/// Each access writes the select CSR and then accesses the alias, so it must not be interleaved with another access
+/// through the same window, for example from an interrupt handler.
+pub trait Indirect: Csr<Value = usize> + Sized {
+    /// Alias CSR through which the selected register is accessed.
+    type Alias: Csr<Value = usize>;
+
+    /// Read the register selected by `select`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn read_indirect(select: usize) -> usize {
+        unsafe { Self::write(select) };
+        Self::Alias::read()
+    }
+
+    /// Write the register selected by `select`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn write_indirect(select: usize, value: usize) {
+        unsafe {
+            Self::write(select);
+            Self::Alias::write(value);
+        }
+    }
+
+    /// Set the bits of `mask` in the register selected by `select`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn set_indirect(select: usize, mask: usize) {
+        unsafe {
+            Self::write(select);
+            Self::Alias::set(mask);
+        }
+    }
+
+    /// Clear the bits of `mask` in the register selected by `select`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Csr::write`].
+    #[inline(always)]
+    unsafe fn clear_indirect(select: usize, mask: usize) {
+        unsafe {
+            Self::write(select);
+            Self::Alias::clear(mask);
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use super::{Counter, csr};
+
+csr! {
+    /// Instructions-retired counter for `rdinstret`.
+    pub struct Instret: usize = 0xC02;
+}
+
+impl Counter for Instret {}
+
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    Instret::read64()
+}
@@ -0,0 +1,86 @@
+use super::{Csr, csr};
+use core::num::NonZero;
+
+csr! {
+    /// Machine architecture ID register.
+    pub struct Marchid: usize = 0xF12;
+}
+
+/// Read the architecture ID, or [`None`] if not implemented.
+#[inline]
+#[must_use]
+pub fn read() -> Option<ArchId> {
+    NonZero::new(Marchid::read()).map(ArchId)
+}
+
+/// Base microarchitecture of the hart.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArchId(NonZero<usize>);
+
+impl ArchId {
+    const COMMERCIAL: usize = 1 << (usize::BITS - 1);
+
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.0.get()
+    }
+
+    /// Whether the ID was allocated by a commercial vendor rather than RISC-V International.
+    #[inline]
+    pub const fn is_commercial(self) -> bool {
+        self.get() & Self::COMMERCIAL != 0
+    }
+
+    #[inline]
+    pub const fn is_open_source(self) -> bool {
+        !self.is_commercial()
+    }
+
+    /// Registered open-source project, if known.
+    #[inline]
+    pub fn project(self) -> Option<Project> {
+        Project::try_from(self.get()).ok()
+    }
+}
+
+/// Open-source architecture IDs registered with RISC-V International.
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Project {
+    Rocket = 1,
+    Boom = 2,
+    Cva6 = 3,
+    Cv32e40p = 4,
+    Spike = 5,
+    Scr1 = 8,
+    SweRvEh1 = 11,
+    BlackParrot = 13,
+    Serv = 18,
+    Neorv32 = 19,
+    Ibex = 22,
+    XiangShan = 25,
+}
+
+impl TryFrom<usize> for Project {
+    type Error = ();
+
+    fn try_from(project: usize) -> Result<Self, Self::Error> {
+        Ok(match project {
+            1 => Self::Rocket,
+            2 => Self::Boom,
+            3 => Self::Cva6,
+            4 => Self::Cv32e40p,
+            5 => Self::Spike,
+            8 => Self::Scr1,
+            11 => Self::SweRvEh1,
+            13 => Self::BlackParrot,
+            18 => Self::Serv,
+            19 => Self::Neorv32,
+            22 => Self::Ibex,
+            25 => Self::XiangShan,
+            _ => return Err(()),
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use super::scause::Cause;
+use super::{Csr, csr};
+
+csr! {
+    /// Machine trap cause register.
+    pub struct Mcause: usize = 0x342;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Cause {
+    Cause::from_bits(Mcause::read())
+}
@@ -0,0 +1,31 @@
+use super::scounteren::Counteren;
+use super::{Csr, csr};
+
+csr! {
+    /// Machine counter-enable register.
+    pub struct Mcounteren: Counteren = 0x306;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Counteren {
+    Mcounteren::read()
+}
+
+#[inline]
+pub unsafe fn write(mcounteren: Counteren) {
+    unsafe { Mcounteren::write(mcounteren) };
+}
+
+/// Discover which counters can be exposed to supervisor mode; the remaining bits are hardwired to zero.
+///
+/// Temporarily enables every counter for supervisor mode. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn probe() -> Counteren {
+    unsafe {
+        let mcounteren = Mcounteren::swap(Counteren::all());
+        let implemented = read();
+        write(mcounteren);
+        implemented
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Csr, csr};
+
+csr! {
+    impl Mcountinhibit = 0x320;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Mcountinhibit {
+    Mcountinhibit::read()
+}
+
+#[inline]
+pub unsafe fn write(mcountinhibit: Mcountinhibit) {
+    unsafe { Mcountinhibit::write(mcountinhibit) };
+}
+
+/// Machine counter-inhibit register: set bits stop the corresponding counter from incrementing.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mcountinhibit(u32);
+
+impl Mcountinhibit {
+    const CY: u32 = 1 << 0;
+    const IR: u32 = 1 << 2;
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: u32, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    /// `mcycle` is inhibited.
+    #[inline]
+    pub const fn cy(self) -> bool {
+        self.0 & Self::CY != 0
+    }
+
+    #[inline]
+    pub const fn with_cy(self, cy: bool) -> Self {
+        self.with_bit(Self::CY, cy)
+    }
+
+    /// `minstret` is inhibited.
+    #[inline]
+    pub const fn ir(self) -> bool {
+        self.0 & Self::IR != 0
+    }
+
+    #[inline]
+    pub const fn with_ir(self, ir: bool) -> Self {
+        self.with_bit(Self::IR, ir)
+    }
+
+    /// `mhpmcounter{n}` is inhibited.
+    #[inline]
+    pub const fn hpm(self, n: usize) -> bool {
+        assert!(n >= 3 && n <= 31, "hpm counter index out of range");
+        self.0 & (1 << n) != 0
+    }
+
+    #[inline]
+    pub const fn with_hpm(self, n: usize, hpm: bool) -> Self {
+        assert!(n >= 3 && n <= 31, "hpm counter index out of range");
+        self.with_bit(1 << n, hpm)
+    }
+}
+
+impl From<usize> for Mcountinhibit {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u32)
+    }
+}
+
+impl From<Mcountinhibit> for usize {
+    #[inline]
+    fn from(mcountinhibit: Mcountinhibit) -> Self {
+        mcountinhibit.0 as usize
+    }
+}
@@ -0,0 +1,43 @@
+use super::scause::Exception;
+use super::{Csr, DelegationSet, csr};
+
+csr! {
+    /// Machine exception delegation register.
+    pub struct Medeleg: DelegationSet<Exception> = 0x302;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> DelegationSet<Exception> {
+    Medeleg::read()
+}
+
+#[inline]
+pub unsafe fn write(medeleg: DelegationSet<Exception>) {
+    unsafe { Medeleg::write(medeleg) };
+}
+
+/// Delegate an exception to supervisor mode.
+#[inline]
+pub unsafe fn delegate(exception: Exception) {
+    unsafe { Medeleg::set(DelegationSet::empty().with(exception)) };
+}
+
+/// Handle an exception in machine mode again.
+#[inline]
+pub unsafe fn undelegate(exception: Exception) {
+    unsafe { Medeleg::clear(DelegationSet::empty().with(exception)) };
+}
+
+/// Discover which exceptions the hart allows to be delegated.
+///
+/// Temporarily writes all ones to `medeleg`, so no exception may be taken while probing.
+#[inline]
+pub unsafe fn probe() -> DelegationSet<Exception> {
+    unsafe {
+        let medeleg = Medeleg::swap(DelegationSet::from(usize::MAX));
+        let implemented = read();
+        write(medeleg);
+        implemented
+    }
+}
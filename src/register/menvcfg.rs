@@ -0,0 +1,294 @@
+use super::{Csr, csr};
+#[cfg(target_pointer_width = "64")]
+use crate::PointerMasking;
+
+csr! {
+    /// Machine environment configuration register, or its lower half on RV32.
+    struct Low: usize = 0x30A;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    /// Upper 32 bits of the machine environment configuration register on RV32.
+    struct High: usize = 0x31A;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Menvcfg {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Menvcfg(Low::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Menvcfg(((High::read() as u64) << 32) | (Low::read() as u64))
+    }
+}
+
+#[inline]
+pub unsafe fn write(menvcfg: Menvcfg) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::write(menvcfg.0 as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Low::write(menvcfg.0 as usize);
+        High::write((menvcfg.0 >> 32) as usize);
+    }
+}
+
+#[inline]
+pub unsafe fn update(f: impl FnOnce(Menvcfg) -> Menvcfg) {
+    unsafe { write(f(read())) };
+}
+
+/// Let supervisor mode program its own timer through `stimecmp`.
+#[inline]
+pub unsafe fn set_stce() {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::set_const::<{ Menvcfg::STCE as usize }>();
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        High::set_const::<{ (Menvcfg::STCE >> 32) as usize }>();
+    }
+}
+
+#[inline]
+pub unsafe fn clear_stce() {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::clear_const::<{ Menvcfg::STCE as usize }>();
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        High::clear_const::<{ (Menvcfg::STCE >> 32) as usize }>();
+    }
+}
+
+/// Check whether the hart implements pointer masking `pmm` for supervisor mode.
+///
+/// The field is WARL: an unsupported length reads back differently. The previous value is restored afterwards.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+pub unsafe fn probe(pmm: PointerMasking) -> bool {
+    unsafe {
+        let menvcfg = read();
+        let mut probe = menvcfg;
+        probe.set_pmm(pmm);
+        write(probe);
+        let supported = read().pmm() == Some(pmm);
+        write(menvcfg);
+        supported
+    }
+}
+
+/// Machine environment configuration register. Controls the execution environment of the privilege levels below
+/// machine mode.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Menvcfg(u64);
+
+impl Menvcfg {
+    const FIOM: u64 = 1 << 0;
+    const LPE: u64 = 1 << 2;
+    const SSE: u64 = 1 << 3;
+    const CBIE: u64 = 4;
+    const CBCFE: u64 = 1 << 6;
+    const CBZE: u64 = 1 << 7;
+    #[cfg(target_pointer_width = "64")]
+    const PMM: u64 = 32;
+    const CDE: u64 = 1 << 60;
+    const ADUE: u64 = 1 << 61;
+    const PBMTE: u64 = 1 << 62;
+    const STCE: u64 = 1 << 63;
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: u64) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: u64, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Fence of I/O implies memory: `fence` on I/O in lower privilege levels also orders memory accesses.
+    #[inline]
+    pub const fn fiom(self) -> bool {
+        self.bit(Self::FIOM)
+    }
+
+    #[inline]
+    pub const fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(Self::FIOM, fiom);
+    }
+
+    /// Landing pads are enforced in the next lower privilege level.
+    #[inline]
+    pub const fn lpe(self) -> bool {
+        self.bit(Self::LPE)
+    }
+
+    #[inline]
+    pub const fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(Self::LPE, lpe);
+    }
+
+    /// Shadow stacks are active in the next lower privilege level.
+    #[inline]
+    pub const fn sse(self) -> bool {
+        self.bit(Self::SSE)
+    }
+
+    #[inline]
+    pub const fn set_sse(&mut self, sse: bool) {
+        self.set_bit(Self::SSE, sse);
+    }
+
+    /// Behaviour of `cbo.inval` below machine mode, or [`None`] for the reserved encoding.
+    #[inline]
+    pub fn cbie(self) -> Option<CacheBlockInvalidate> {
+        CacheBlockInvalidate::try_from(((self.0 >> Self::CBIE) & 0b11) as usize).ok()
+    }
+
+    #[inline]
+    pub const fn set_cbie(&mut self, cbie: CacheBlockInvalidate) {
+        self.0 = (self.0 & !(0b11 << Self::CBIE)) | ((cbie as u64) << Self::CBIE);
+    }
+
+    /// `cbo.clean` and `cbo.flush` are allowed below machine mode.
+    #[inline]
+    pub const fn cbcfe(self) -> bool {
+        self.bit(Self::CBCFE)
+    }
+
+    #[inline]
+    pub const fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(Self::CBCFE, cbcfe);
+    }
+
+    /// `cbo.zero` is allowed below machine mode.
+    #[inline]
+    pub const fn cbze(self) -> bool {
+        self.bit(Self::CBZE)
+    }
+
+    #[inline]
+    pub const fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(Self::CBZE, cbze);
+    }
+
+    /// Pointer masking for the next lower privilege level, or [`None`] for the reserved encoding.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn pmm(self) -> Option<PointerMasking> {
+        PointerMasking::try_from(((self.0 >> Self::PMM) & 0b11) as usize).ok()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_pmm(&mut self, pmm: PointerMasking) {
+        self.0 = (self.0 & !(0b11 << Self::PMM)) | ((pmm as u64) << Self::PMM);
+    }
+
+    /// Counter delegation: `scountinhibit` and the Smcdeleg counters are accessible from supervisor mode.
+    #[inline]
+    pub const fn cde(self) -> bool {
+        self.bit(Self::CDE)
+    }
+
+    #[inline]
+    pub const fn set_cde(&mut self, cde: bool) {
+        self.set_bit(Self::CDE, cde);
+    }
+
+    /// Hardware updates the A and D bits of supervisor-level page table entries.
+    #[inline]
+    pub const fn adue(self) -> bool {
+        self.bit(Self::ADUE)
+    }
+
+    #[inline]
+    pub const fn set_adue(&mut self, adue: bool) {
+        self.set_bit(Self::ADUE, adue);
+    }
+
+    /// Svpbmt page-based memory types are honoured in supervisor-level page tables.
+    #[inline]
+    pub const fn pbmte(self) -> bool {
+        self.bit(Self::PBMTE)
+    }
+
+    #[inline]
+    pub const fn set_pbmte(&mut self, pbmte: bool) {
+        self.set_bit(Self::PBMTE, pbmte);
+    }
+
+    /// Supervisor timer compare: `stimecmp` is accessible and drives the supervisor timer interrupt.
+    #[inline]
+    pub const fn stce(self) -> bool {
+        self.bit(Self::STCE)
+    }
+
+    #[inline]
+    pub const fn set_stce(&mut self, stce: bool) {
+        self.set_bit(Self::STCE, stce);
+    }
+}
+
+impl From<u64> for Menvcfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Menvcfg> for u64 {
+    #[inline]
+    fn from(menvcfg: Menvcfg) -> Self {
+        menvcfg.0
+    }
+}
+
+/// Behaviour of the `cbo.inval` cache-block invalidate instruction.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheBlockInvalidate {
+    /// `cbo.inval` raises an illegal instruction exception.
+    Illegal = 0b00,
+    /// `cbo.inval` is performed as a flush.
+    Flush = 0b01,
+    /// `cbo.inval` invalidates the cache block.
+    Invalidate = 0b11,
+}
+
+impl TryFrom<usize> for CacheBlockInvalidate {
+    type Error = ();
+
+    fn try_from(cbie: usize) -> Result<Self, Self::Error> {
+        Ok(match cbie {
+            0b00 => Self::Illegal,
+            0b01 => Self::Flush,
+            0b11 => Self::Invalidate,
+            _ => return Err(()),
+        })
+    }
+}
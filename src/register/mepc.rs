@@ -0,0 +1,22 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Machine exception program counter.
+    pub struct Mepc: usize = 0x341;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Mepc::read()
+}
+
+#[inline]
+pub unsafe fn write(mepc: usize) {
+    unsafe { Mepc::write(mepc & !0b1) };
+}
+
+#[inline]
+pub unsafe fn update(f: impl FnOnce(usize) -> usize) {
+    unsafe { write(f(read())) };
+}
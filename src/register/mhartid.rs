@@ -0,0 +1,29 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Hart ID register.
+    pub struct Mhartid: usize = 0xF14;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> HartId {
+    HartId(Mhartid::read())
+}
+
+/// Integer ID of the hart running the code.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HartId(usize);
+
+impl HartId {
+    #[inline]
+    pub const fn new(id: usize) -> Self {
+        Self(id)
+    }
+
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.0
+    }
+}
@@ -0,0 +1,26 @@
+use super::{Counter, Csr};
+
+/// Machine hardware performance-monitoring counter `mhpmcounterN`.
+#[derive(Debug, Clone, Copy)]
+pub struct Mhpmcounter<const N: usize>;
+
+impl<const N: usize> Csr for Mhpmcounter<N> {
+    const ADDRESS: u16 = {
+        assert!(N >= 3 && N <= 31, "mhpmcounter index out of range");
+        0xB00 + N as u16
+    };
+    type Value = usize;
+}
+
+impl<const N: usize> Counter for Mhpmcounter<N> {}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> u64 {
+    Mhpmcounter::<N>::read64()
+}
+
+#[inline]
+pub unsafe fn write<const N: usize>(mhpmcounter: u64) {
+    unsafe { Mhpmcounter::<N>::write64(mhpmcounter) };
+}
@@ -0,0 +1,174 @@
+use super::Csr;
+
+/// Machine performance-monitoring event selector `mhpmeventN`, or its lower half on RV32.
+///
+/// Event encodings are platform-specific; `0` means no event.
+#[derive(Debug, Clone, Copy)]
+pub struct Mhpmevent<const N: usize>;
+
+impl<const N: usize> Csr for Mhpmevent<N> {
+    const ADDRESS: u16 = {
+        assert!(N >= 3 && N <= 31, "mhpmevent index out of range");
+        0x320 + N as u16
+    };
+    type Value = usize;
+}
+
+/// Upper 32 bits of the machine performance-monitoring event selector `mhpmeventN` on RV32.
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_pointer_width = "32")]
+pub struct Mhpmeventh<const N: usize>;
+
+#[cfg(target_pointer_width = "32")]
+impl<const N: usize> Csr for Mhpmeventh<N> {
+    const ADDRESS: u16 = {
+        assert!(N >= 3 && N <= 31, "mhpmevent index out of range");
+        0x720 + N as u16
+    };
+    type Value = usize;
+}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> Event {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Event(Mhpmevent::<N>::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Event(((Mhpmeventh::<N>::read() as u64) << 32) | (Mhpmevent::<N>::read() as u64))
+    }
+}
+
+/// Select the event counted by `mhpmcounterN`.
+#[inline]
+pub unsafe fn write<const N: usize>(event: Event) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Mhpmevent::<N>::write(event.0 as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Mhpmevent::<N>::write(event.0 as usize);
+        Mhpmeventh::<N>::write((event.0 >> 32) as usize);
+    }
+}
+
+/// Event selector, with the Sscofpmf overflow and mode-filtering bits.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event(u64);
+
+impl Event {
+    const SELECTOR: u64 = (1 << 58) - 1;
+    const VUINH: u64 = 1 << 58;
+    const VSINH: u64 = 1 << 59;
+    const UINH: u64 = 1 << 60;
+    const SINH: u64 = 1 << 61;
+    const MINH: u64 = 1 << 62;
+    const OF: u64 = 1 << 63;
+
+    /// Count the platform-specific event `selector`, where `0` means no event. Out-of-range bits are truncated.
+    #[inline]
+    pub const fn new(selector: u64) -> Self {
+        Self(selector & Self::SELECTOR)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: u64, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    #[inline]
+    pub const fn selector(self) -> u64 {
+        self.0 & Self::SELECTOR
+    }
+
+    /// The counter overflowed. While set, further overflows do not raise a counter-overflow interrupt.
+    #[inline]
+    pub const fn of(self) -> bool {
+        self.0 & Self::OF != 0
+    }
+
+    #[inline]
+    pub const fn with_of(self, of: bool) -> Self {
+        self.with_bit(Self::OF, of)
+    }
+
+    /// Counting is inhibited in machine mode.
+    #[inline]
+    pub const fn minh(self) -> bool {
+        self.0 & Self::MINH != 0
+    }
+
+    #[inline]
+    pub const fn with_minh(self, minh: bool) -> Self {
+        self.with_bit(Self::MINH, minh)
+    }
+
+    /// Counting is inhibited in supervisor mode.
+    #[inline]
+    pub const fn sinh(self) -> bool {
+        self.0 & Self::SINH != 0
+    }
+
+    #[inline]
+    pub const fn with_sinh(self, sinh: bool) -> Self {
+        self.with_bit(Self::SINH, sinh)
+    }
+
+    /// Counting is inhibited in user mode.
+    #[inline]
+    pub const fn uinh(self) -> bool {
+        self.0 & Self::UINH != 0
+    }
+
+    #[inline]
+    pub const fn with_uinh(self, uinh: bool) -> Self {
+        self.with_bit(Self::UINH, uinh)
+    }
+
+    /// Counting is inhibited in virtual supervisor mode.
+    #[inline]
+    pub const fn vsinh(self) -> bool {
+        self.0 & Self::VSINH != 0
+    }
+
+    #[inline]
+    pub const fn with_vsinh(self, vsinh: bool) -> Self {
+        self.with_bit(Self::VSINH, vsinh)
+    }
+
+    /// Counting is inhibited in virtual user mode.
+    #[inline]
+    pub const fn vuinh(self) -> bool {
+        self.0 & Self::VUINH != 0
+    }
+
+    #[inline]
+    pub const fn with_vuinh(self, vuinh: bool) -> Self {
+        self.with_bit(Self::VUINH, vuinh)
+    }
+}
+
+impl From<u64> for Event {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Event> for u64 {
+    #[inline]
+    fn from(event: Event) -> Self {
+        event.0
+    }
+}
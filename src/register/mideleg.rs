@@ -0,0 +1,43 @@
+use super::scause::Interrupt;
+use super::{Csr, DelegationSet, csr};
+
+csr! {
+    /// Machine interrupt delegation register.
+    pub struct Mideleg: DelegationSet<Interrupt> = 0x303;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> DelegationSet<Interrupt> {
+    Mideleg::read()
+}
+
+#[inline]
+pub unsafe fn write(mideleg: DelegationSet<Interrupt>) {
+    unsafe { Mideleg::write(mideleg) };
+}
+
+/// Delegate an interrupt to supervisor mode.
+#[inline]
+pub unsafe fn delegate(interrupt: Interrupt) {
+    unsafe { Mideleg::set(DelegationSet::empty().with(interrupt)) };
+}
+
+/// Handle an interrupt in machine mode again.
+#[inline]
+pub unsafe fn undelegate(interrupt: Interrupt) {
+    unsafe { Mideleg::clear(DelegationSet::empty().with(interrupt)) };
+}
+
+/// Discover which interrupts the hart allows to be delegated.
+///
+/// Temporarily writes all ones to `mideleg`, so no trap may be taken while probing.
+#[inline]
+pub unsafe fn probe() -> DelegationSet<Interrupt> {
+    unsafe {
+        let mideleg = Mideleg::swap(DelegationSet::from(usize::MAX));
+        let implemented = read();
+        write(mideleg);
+        implemented
+    }
+}
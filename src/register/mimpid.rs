@@ -0,0 +1,13 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Machine implementation ID register.
+    pub struct Mimpid: usize = 0xF13;
+}
+
+/// Read the version of the processor implementation, or `0` if not implemented.
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Mimpid::read()
+}
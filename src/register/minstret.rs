@@ -0,0 +1,19 @@
+use super::{Counter, csr};
+
+csr! {
+    /// Machine instructions-retired counter.
+    pub struct Minstret: usize = 0xB02;
+}
+
+impl Counter for Minstret {}
+
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    Minstret::read64()
+}
+
+#[inline]
+pub unsafe fn write(minstret: u64) {
+    unsafe { Minstret::write64(minstret) };
+}
@@ -1,11 +1,16 @@
+use super::{Csr, csr};
 use crate::XLEN;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
 
+csr! {
+    /// Machine ISA register.
+    pub struct Misa: usize = 0x301;
+}
+
 #[must_use]
 #[inline(always)]
 pub fn read() -> (XLEN, Extension) {
-    let misa: usize;
-    unsafe { core::arch::asm!("csrr {}, misa", out(reg) misa, options(nomem, nostack)) };
+    let misa = Misa::read();
 
     let extension = Extension(misa & 0x3FFFFFF);
     let xlen = XLEN::try_from((misa >> (usize::BITS as usize - 2)) & 0b11).unwrap_or_default();
@@ -0,0 +1,27 @@
+use super::{Indirect, csr};
+
+csr! {
+    /// Machine indirect register select.
+    pub struct Miselect: usize = 0x350;
+}
+
+csr! {
+    /// Machine indirect register alias, accessing the register selected by `miselect`.
+    pub struct Mireg: usize = 0x351;
+}
+
+impl Indirect for Miselect {
+    type Alias = Mireg;
+}
+
+/// Read the machine-level register selected by `select`.
+#[inline]
+pub unsafe fn read(select: usize) -> usize {
+    unsafe { Miselect::read_indirect(select) }
+}
+
+/// Write the machine-level register selected by `select`.
+#[inline]
+pub unsafe fn write(select: usize, value: usize) {
+    unsafe { Miselect::write_indirect(select, value) };
+}
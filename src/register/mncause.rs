@@ -0,0 +1,14 @@
+use super::scause::Cause;
+use super::{Csr, csr};
+
+csr! {
+    /// Resumable NMI cause register.
+    pub struct Mncause: usize = 0x742;
+}
+
+/// Cause of the non-maskable interrupt. Always has the interrupt bit set, and the code is implementation-defined.
+#[inline]
+#[must_use]
+pub fn read() -> Cause {
+    Cause::from_bits(Mncause::read())
+}
@@ -0,0 +1,17 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Resumable NMI exception program counter.
+    pub struct Mnepc: usize = 0x741;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Mnepc::read()
+}
+
+#[inline]
+pub unsafe fn write(mnepc: usize) {
+    unsafe { Mnepc::write(mnepc & !0b1) };
+}
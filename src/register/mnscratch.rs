@@ -0,0 +1,125 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Resumable NMI scratch register.
+    pub struct Mnscratch: usize = 0x740;
+}
+
+#[must_use]
+#[inline]
+pub fn read() -> usize {
+    Mnscratch::read()
+}
+
+#[inline]
+pub fn write(data: usize) {
+    unsafe { Mnscratch::write(data) };
+}
+
+#[inline]
+pub fn swap(data: usize) -> usize {
+    unsafe { Mnscratch::swap(data) }
+}
+
+/// Define `$name` as an NMI entry point that calls `extern "C" fn $handler()` and resumes with `mnret`.
+///
+/// The entry point switches to the stack whose top is held in `mnscratch`, saves the integer caller-saved registers
+/// there and swaps the interrupted stack pointer back before returning, so `mepc`, `mcause` and the interrupted stack
+/// are left untouched. `mnscratch` must be set to a 16-byte aligned stack before NMIs are enabled with
+/// [`set_nmie`](super::mnstatus::set_nmie). Floating-point registers are not saved, so the handler must not use them.
+///
+/// The handler reads the cause from [`mncause`](super::mncause) and may redirect the return through
+/// [`mnepc`](super::mnepc).
+pub macro nmi_handler($vis:vis $name:ident => $handler:path) {
+    #[cfg(target_pointer_width = "64")]
+    #[unsafe(naked)]
+    $vis unsafe extern "C" fn $name() -> ! {
+        core::arch::naked_asm!(
+            "csrrw sp, 0x740, sp",
+            "addi sp, sp, -128",
+            "sd ra, 0(sp)",
+            "sd t0, 8(sp)",
+            "sd t1, 16(sp)",
+            "sd t2, 24(sp)",
+            "sd t3, 32(sp)",
+            "sd t4, 40(sp)",
+            "sd t5, 48(sp)",
+            "sd t6, 56(sp)",
+            "sd a0, 64(sp)",
+            "sd a1, 72(sp)",
+            "sd a2, 80(sp)",
+            "sd a3, 88(sp)",
+            "sd a4, 96(sp)",
+            "sd a5, 104(sp)",
+            "sd a6, 112(sp)",
+            "sd a7, 120(sp)",
+            "call {handler}",
+            "ld ra, 0(sp)",
+            "ld t0, 8(sp)",
+            "ld t1, 16(sp)",
+            "ld t2, 24(sp)",
+            "ld t3, 32(sp)",
+            "ld t4, 40(sp)",
+            "ld t5, 48(sp)",
+            "ld t6, 56(sp)",
+            "ld a0, 64(sp)",
+            "ld a1, 72(sp)",
+            "ld a2, 80(sp)",
+            "ld a3, 88(sp)",
+            "ld a4, 96(sp)",
+            "ld a5, 104(sp)",
+            "ld a6, 112(sp)",
+            "ld a7, 120(sp)",
+            "addi sp, sp, 128",
+            "csrrw sp, 0x740, sp",
+            ".insn i 0x73, 0, x0, x0, 0x702",
+            handler = sym $handler,
+        )
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[unsafe(naked)]
+    $vis unsafe extern "C" fn $name() -> ! {
+        core::arch::naked_asm!(
+            "csrrw sp, 0x740, sp",
+            "addi sp, sp, -64",
+            "sw ra, 0(sp)",
+            "sw t0, 4(sp)",
+            "sw t1, 8(sp)",
+            "sw t2, 12(sp)",
+            "sw t3, 16(sp)",
+            "sw t4, 20(sp)",
+            "sw t5, 24(sp)",
+            "sw t6, 28(sp)",
+            "sw a0, 32(sp)",
+            "sw a1, 36(sp)",
+            "sw a2, 40(sp)",
+            "sw a3, 44(sp)",
+            "sw a4, 48(sp)",
+            "sw a5, 52(sp)",
+            "sw a6, 56(sp)",
+            "sw a7, 60(sp)",
+            "call {handler}",
+            "lw ra, 0(sp)",
+            "lw t0, 4(sp)",
+            "lw t1, 8(sp)",
+            "lw t2, 12(sp)",
+            "lw t3, 16(sp)",
+            "lw t4, 20(sp)",
+            "lw t5, 24(sp)",
+            "lw t6, 28(sp)",
+            "lw a0, 32(sp)",
+            "lw a1, 36(sp)",
+            "lw a2, 40(sp)",
+            "lw a3, 44(sp)",
+            "lw a4, 48(sp)",
+            "lw a5, 52(sp)",
+            "lw a6, 56(sp)",
+            "lw a7, 60(sp)",
+            "addi sp, sp, 64",
+            "csrrw sp, 0x740, sp",
+            ".insn i 0x73, 0, x0, x0, 0x702",
+            handler = sym $handler,
+        )
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Csr, csr};
+use crate::Privilege;
+
+csr! {
+    impl Mnstatus = 0x744;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Mnstatus {
+    Mnstatus::read()
+}
+
+#[inline]
+pub unsafe fn write(mnstatus: Mnstatus) {
+    unsafe { Mnstatus::write(mnstatus) };
+}
+
+/// Enable non-maskable interrupts. The hart clears the bit on NMI entry; `mnret` or software sets it again.
+#[inline]
+pub unsafe fn set_nmie() {
+    unsafe { Mnstatus::set_const::<{ Mnstatus::NMIE }>() };
+}
+
+/// Resumable NMI status register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mnstatus(usize);
+
+impl Mnstatus {
+    const NMIE: usize = 1 << 3;
+    const MNPV: usize = 1 << 7;
+    const MNPP: usize = 11;
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Non-maskable interrupts are enabled. While clear, traps in machine mode are unrecoverable.
+    #[inline]
+    pub const fn nmie(self) -> bool {
+        self.0 & Self::NMIE != 0
+    }
+
+    #[inline]
+    pub const fn set_nmie(&mut self, nmie: bool) {
+        if nmie { self.0 |= Self::NMIE } else { self.0 &= !Self::NMIE }
+    }
+
+    /// Virtualization mode the hart was in prior to the NMI.
+    #[inline]
+    pub const fn mnpv(self) -> bool {
+        self.0 & Self::MNPV != 0
+    }
+
+    #[inline]
+    pub const fn set_mnpv(&mut self, mnpv: bool) {
+        if mnpv { self.0 |= Self::MNPV } else { self.0 &= !Self::MNPV }
+    }
+
+    /// Privilege level the hart was in prior to the NMI.
+    #[inline]
+    pub fn mnpp(self) -> Privilege {
+        Privilege::from(self.0 >> Self::MNPP)
+    }
+
+    /// Set the privilege level `mnret` returns to.
+    ///
+    /// Panics on [`Privilege::Hypervisor`], which is a reserved MNPP encoding.
+    #[inline]
+    pub const fn set_mnpp(&mut self, mnpp: Privilege) {
+        assert!(!matches!(mnpp, Privilege::Hypervisor), "reserved mnpp encoding");
+        self.0 = (self.0 & !(0b11 << Self::MNPP)) | ((mnpp as usize) << Self::MNPP);
+    }
+}
+
+impl From<usize> for Mnstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Mnstatus> for usize {
+    #[inline]
+    fn from(mnstatus: Mnstatus) -> Self {
+        mnstatus.0
+    }
+}
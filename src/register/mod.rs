@@ -1,17 +1,108 @@
+mod counter;
+mod csr;
+mod delegation;
+mod indirect;
+mod stateen;
+
+pub mod cycle;
+#[cfg(target_feature = "f")]
+#[doc(cfg(target_feature = "f"))]
+pub mod fcsr;
+#[cfg(target_feature = "f")]
+#[doc(cfg(target_feature = "f"))]
+pub mod fflags;
+#[cfg(target_feature = "f")]
+#[doc(cfg(target_feature = "f"))]
+pub mod frm;
+pub mod hcounteren;
+pub mod hedeleg;
+pub mod henvcfg;
+pub mod hgatp;
+pub mod hgeie;
+pub mod hgeip;
+pub mod hideleg;
+pub mod hie;
+pub mod hip;
+pub mod hpmcounter;
+pub mod hstateen;
+pub mod hstatus;
+pub mod htimedelta;
+pub mod htinst;
+pub mod htval;
+pub mod hvip;
+pub mod imsic;
+pub mod instret;
 pub mod marchid;
+pub mod mcause;
+pub mod mcounteren;
+pub mod mcountinhibit;
 pub mod mcycle;
 pub mod medeleg;
+pub mod menvcfg;
+pub mod mepc;
 pub mod mhartid;
+pub mod mhpmcounter;
+pub mod mhpmevent;
 pub mod mideleg;
 pub mod mimpid;
+pub mod minstret;
 pub mod misa;
+pub mod miselect;
+pub mod mncause;
+pub mod mnepc;
+pub mod mnscratch;
+pub mod mnstatus;
+pub mod mscratch;
+pub mod mseccfg;
+pub mod mstateen;
+pub mod mstatus;
+pub mod mtopei;
+pub mod mtopi;
+pub mod mtval;
 pub mod mtvec;
 pub mod mvendorid;
+pub mod pmp;
+pub mod pmu;
+pub mod satp;
 pub mod scause;
 pub mod scounteren;
+pub mod scountovf;
+pub mod seed;
+pub mod senvcfg;
 pub mod sepc;
 pub mod sie;
+pub mod sip;
+pub mod siselect;
 pub mod sscratch;
+pub mod ssp;
+pub mod sstateen;
 pub mod sstatus;
+pub mod stimecmp;
+pub mod stopei;
+pub mod stopi;
 pub mod stval;
 pub mod stvec;
+pub mod time;
+pub mod trigger;
+pub mod vcsr;
+pub mod vl;
+pub mod vlenb;
+pub mod vsatp;
+pub mod vscause;
+pub mod vsepc;
+pub mod vsie;
+pub mod vsip;
+pub mod vsscratch;
+pub mod vsstatus;
+pub mod vstart;
+pub mod vstval;
+pub mod vstvec;
+pub mod vtype;
+pub mod vxrm;
+pub mod vxsat;
+
+pub use counter::*;
+pub use csr::*;
+pub use delegation::*;
+pub use indirect::*;
+pub use stateen::*;
@@ -0,0 +1,22 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Machine scratch register.
+    pub struct Mscratch: usize = 0x340;
+}
+
+#[must_use]
+#[inline]
+pub fn read() -> usize {
+    Mscratch::read()
+}
+
+#[inline]
+pub fn write(data: usize) {
+    unsafe { Mscratch::write(data) };
+}
+
+#[inline]
+pub fn swap(data: usize) -> usize {
+    unsafe { Mscratch::swap(data) }
+}
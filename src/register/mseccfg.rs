@@ -0,0 +1,203 @@
+use super::{Csr, csr};
+#[cfg(target_pointer_width = "64")]
+use crate::PointerMasking;
+
+csr! {
+    /// Machine security configuration register, or its lower half on RV32.
+    struct Low: usize = 0x747;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    /// Upper 32 bits of the machine security configuration register on RV32.
+    struct High: usize = 0x757;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Mseccfg {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Mseccfg(Low::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Mseccfg(((High::read() as u64) << 32) | (Low::read() as u64))
+    }
+}
+
+#[inline]
+pub unsafe fn write(mseccfg: Mseccfg) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Low::write(mseccfg.0 as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Low::write(mseccfg.0 as usize);
+        High::write((mseccfg.0 >> 32) as usize);
+    }
+}
+
+#[inline]
+pub unsafe fn update(f: impl FnOnce(Mseccfg) -> Mseccfg) {
+    unsafe { write(f(read())) };
+}
+
+/// Let supervisor mode access the `seed` entropy source.
+#[inline]
+pub unsafe fn set_sseed() {
+    unsafe { Low::set_const::<{ Mseccfg::SSEED as usize }>() };
+}
+
+#[inline]
+pub unsafe fn clear_sseed() {
+    unsafe { Low::clear_const::<{ Mseccfg::SSEED as usize }>() };
+}
+
+/// Let user mode access the `seed` entropy source.
+#[inline]
+pub unsafe fn set_useed() {
+    unsafe { Low::set_const::<{ Mseccfg::USEED as usize }>() };
+}
+
+#[inline]
+pub unsafe fn clear_useed() {
+    unsafe { Low::clear_const::<{ Mseccfg::USEED as usize }>() };
+}
+
+/// Check whether the hart implements pointer masking `pmm` for machine mode.
+///
+/// The field is WARL: an unsupported length reads back differently. The previous value is restored afterwards.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+pub unsafe fn probe(pmm: PointerMasking) -> bool {
+    unsafe {
+        let mseccfg = read();
+        let mut probe = mseccfg;
+        probe.set_pmm(pmm);
+        write(probe);
+        let supported = read().pmm() == Some(pmm);
+        write(mseccfg);
+        supported
+    }
+}
+
+/// Machine security configuration register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mseccfg(u64);
+
+impl Mseccfg {
+    const MML: u64 = 1 << 0;
+    const MMWP: u64 = 1 << 1;
+    const RLB: u64 = 1 << 2;
+    const USEED: u64 = 1 << 8;
+    const SSEED: u64 = 1 << 9;
+    #[cfg(target_pointer_width = "64")]
+    const PMM: u64 = 32;
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: u64) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: u64, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Machine mode lockdown: PMP rules apply to machine mode and deny it execution outside locked regions. Sticky.
+    #[inline]
+    pub const fn mml(self) -> bool {
+        self.bit(Self::MML)
+    }
+
+    #[inline]
+    pub const fn set_mml(&mut self, mml: bool) {
+        self.set_bit(Self::MML, mml);
+    }
+
+    /// Machine mode whitelist policy: machine-mode accesses not matching any PMP entry are denied. Sticky.
+    #[inline]
+    pub const fn mmwp(self) -> bool {
+        self.bit(Self::MMWP)
+    }
+
+    #[inline]
+    pub const fn set_mmwp(&mut self, mmwp: bool) {
+        self.set_bit(Self::MMWP, mmwp);
+    }
+
+    /// Rule locking bypass: locked PMP entries may be modified.
+    #[inline]
+    pub const fn rlb(self) -> bool {
+        self.bit(Self::RLB)
+    }
+
+    #[inline]
+    pub const fn set_rlb(&mut self, rlb: bool) {
+        self.set_bit(Self::RLB, rlb);
+    }
+
+    /// User mode may access `seed`.
+    #[inline]
+    pub const fn useed(self) -> bool {
+        self.bit(Self::USEED)
+    }
+
+    #[inline]
+    pub const fn set_useed(&mut self, useed: bool) {
+        self.set_bit(Self::USEED, useed);
+    }
+
+    /// Supervisor mode may access `seed`.
+    #[inline]
+    pub const fn sseed(self) -> bool {
+        self.bit(Self::SSEED)
+    }
+
+    #[inline]
+    pub const fn set_sseed(&mut self, sseed: bool) {
+        self.set_bit(Self::SSEED, sseed);
+    }
+
+    /// Pointer masking for machine mode, or [`None`] for the reserved encoding.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn pmm(self) -> Option<PointerMasking> {
+        PointerMasking::try_from(((self.0 >> Self::PMM) & 0b11) as usize).ok()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_pmm(&mut self, pmm: PointerMasking) {
+        self.0 = (self.0 & !(0b11 << Self::PMM)) | ((pmm as u64) << Self::PMM);
+    }
+}
+
+impl From<u64> for Mseccfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Mseccfg> for u64 {
+    #[inline]
+    fn from(mseccfg: Mseccfg) -> Self {
+        mseccfg.0
+    }
+}
@@ -0,0 +1,70 @@
+use super::{Csr, Stateen};
+
+/// Machine state-enable register `mstateenN`, or its lower half on RV32.
+#[derive(Debug, Clone, Copy)]
+pub struct Mstateen<const N: usize>;
+
+impl<const N: usize> Csr for Mstateen<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 4, "mstateen index out of range");
+        0x30C + N as u16
+    };
+    type Value = usize;
+}
+
+/// Upper 32 bits of the machine state-enable register `mstateenN` on RV32.
+#[derive(Debug, Clone, Copy)]
+#[cfg(target_pointer_width = "32")]
+pub struct Mstateenh<const N: usize>;
+
+#[cfg(target_pointer_width = "32")]
+impl<const N: usize> Csr for Mstateenh<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 4, "mstateen index out of range");
+        0x31C + N as u16
+    };
+    type Value = usize;
+}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> Stateen {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Stateen::from_bits(Mstateen::<N>::read() as u64)
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        Stateen::from_bits(((Mstateenh::<N>::read() as u64) << 32) | (Mstateen::<N>::read() as u64))
+    }
+}
+
+/// Grant supervisor and hypervisor access to the state guarded by the bits set in `stateen`, and deny it everything else.
+#[inline]
+pub unsafe fn write<const N: usize>(stateen: Stateen) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Mstateen::<N>::write(stateen.bits() as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Mstateen::<N>::write(stateen.bits() as usize);
+        Mstateenh::<N>::write((stateen.bits() >> 32) as usize);
+    }
+}
+
+/// Discover which bits of `mstateenN` the hart implements.
+///
+/// All bits are WARL, so writing all ones reads back as the implemented set. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn implemented<const N: usize>() -> Stateen {
+    unsafe {
+        let stateen = read::<N>();
+        write::<N>(Stateen::all());
+        let implemented = read::<N>();
+        write::<N>(stateen);
+        implemented
+    }
+}
@@ -0,0 +1,401 @@
+use super::sstatus::ExtensionState;
+use super::{Csr, csr};
+use crate::Privilege;
+#[cfg(target_pointer_width = "64")]
+use crate::XLEN;
+
+csr! {
+    impl Mstatus = 0x300;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    impl Mstatush = 0x310;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Mstatus {
+    Mstatus::read()
+}
+
+#[inline]
+pub unsafe fn write(mstatus: Mstatus) {
+    unsafe { Mstatus::write(mstatus) };
+}
+
+/// Enable machine interrupts.
+#[inline]
+pub unsafe fn set_mie() {
+    unsafe { Mstatus::set_const::<{ Mstatus::MIE }>() };
+}
+
+/// Disable machine interrupts.
+#[inline]
+pub unsafe fn clear_mie() {
+    unsafe { Mstatus::clear_const::<{ Mstatus::MIE }>() };
+}
+
+/// Translate and protect loads and stores as though in the [`Mstatus::mpp`] privilege level.
+#[inline]
+pub unsafe fn set_mprv() {
+    unsafe { Mstatus::set_const::<{ Mstatus::MPRV }>() };
+}
+
+#[inline]
+pub unsafe fn clear_mprv() {
+    unsafe { Mstatus::clear_const::<{ Mstatus::MPRV }>() };
+}
+
+/// Machine status register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mstatus(usize);
+
+impl Mstatus {
+    const SIE: usize = 1 << 1;
+    const MIE: usize = 1 << 3;
+    const SPIE: usize = 1 << 5;
+    const UBE: usize = 1 << 6;
+    const MPIE: usize = 1 << 7;
+    const SPP: usize = 1 << 8;
+    const VS: usize = 9;
+    const MPP: usize = 11;
+    const FS: usize = 13;
+    const XS: usize = 15;
+    const MPRV: usize = 1 << 17;
+    const SUM: usize = 1 << 18;
+    const MXR: usize = 1 << 19;
+    const TVM: usize = 1 << 20;
+    const TW: usize = 1 << 21;
+    const TSR: usize = 1 << 22;
+    #[cfg(target_pointer_width = "64")]
+    const UXL: usize = 32;
+    #[cfg(target_pointer_width = "64")]
+    const SXL: usize = 34;
+    #[cfg(target_pointer_width = "64")]
+    const SBE: usize = 1 << 36;
+    #[cfg(target_pointer_width = "64")]
+    const MBE: usize = 1 << 37;
+    const SD: usize = 1 << (usize::BITS - 1);
+
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: usize) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: usize, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Supervisor interrupt enable.
+    #[inline]
+    pub const fn sie(self) -> bool {
+        self.bit(Self::SIE)
+    }
+
+    #[inline]
+    pub const fn set_sie(&mut self, sie: bool) {
+        self.set_bit(Self::SIE, sie);
+    }
+
+    /// Machine interrupt enable.
+    #[inline]
+    pub const fn mie(self) -> bool {
+        self.bit(Self::MIE)
+    }
+
+    #[inline]
+    pub const fn set_mie(&mut self, mie: bool) {
+        self.set_bit(Self::MIE, mie);
+    }
+
+    /// Supervisor interrupt enable prior to the trap.
+    #[inline]
+    pub const fn spie(self) -> bool {
+        self.bit(Self::SPIE)
+    }
+
+    #[inline]
+    pub const fn set_spie(&mut self, spie: bool) {
+        self.set_bit(Self::SPIE, spie);
+    }
+
+    /// User-mode memory accesses are big-endian.
+    #[inline]
+    pub const fn ube(self) -> bool {
+        self.bit(Self::UBE)
+    }
+
+    #[inline]
+    pub const fn set_ube(&mut self, ube: bool) {
+        self.set_bit(Self::UBE, ube);
+    }
+
+    /// Machine interrupt enable prior to the trap.
+    #[inline]
+    pub const fn mpie(self) -> bool {
+        self.bit(Self::MPIE)
+    }
+
+    #[inline]
+    pub const fn set_mpie(&mut self, mpie: bool) {
+        self.set_bit(Self::MPIE, mpie);
+    }
+
+    /// Privilege level the hart was in prior to a supervisor trap.
+    #[inline]
+    pub const fn spp(self) -> Privilege {
+        if self.bit(Self::SPP) { Privilege::Supervisor } else { Privilege::User }
+    }
+
+    /// Set the privilege level `sret` returns to. Anything above user mode is encoded as supervisor mode.
+    #[inline]
+    pub const fn set_spp(&mut self, spp: Privilege) {
+        self.set_bit(Self::SPP, !matches!(spp, Privilege::User));
+    }
+
+    /// Vector extension state.
+    #[inline]
+    pub const fn vs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::VS)
+    }
+
+    #[inline]
+    pub const fn set_vs(&mut self, vs: ExtensionState) {
+        self.0 = (self.0 & !(0b11 << Self::VS)) | ((vs as usize) << Self::VS);
+    }
+
+    /// Privilege level the hart was in prior to a machine trap.
+    #[inline]
+    pub fn mpp(self) -> Privilege {
+        Privilege::from(self.0 >> Self::MPP)
+    }
+
+    /// Set the privilege level `mret` returns to.
+    #[inline]
+    pub const fn set_mpp(&mut self, mpp: Privilege) {
+        self.0 = (self.0 & !(0b11 << Self::MPP)) | ((mpp as usize) << Self::MPP);
+    }
+
+    /// Floating-point unit state.
+    #[inline]
+    pub const fn fs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::FS)
+    }
+
+    #[inline]
+    pub const fn set_fs(&mut self, fs: ExtensionState) {
+        self.0 = (self.0 & !(0b11 << Self::FS)) | ((fs as usize) << Self::FS);
+    }
+
+    /// Summary of additional user-mode extension state.
+    #[inline]
+    pub const fn xs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::XS)
+    }
+
+    /// Modify privilege: loads and stores execute as though in [`Mstatus::mpp`].
+    #[inline]
+    pub const fn mprv(self) -> bool {
+        self.bit(Self::MPRV)
+    }
+
+    #[inline]
+    pub const fn set_mprv(&mut self, mprv: bool) {
+        self.set_bit(Self::MPRV, mprv);
+    }
+
+    /// Permit supervisor user memory access.
+    #[inline]
+    pub const fn sum(self) -> bool {
+        self.bit(Self::SUM)
+    }
+
+    #[inline]
+    pub const fn set_sum(&mut self, sum: bool) {
+        self.set_bit(Self::SUM, sum);
+    }
+
+    /// Make executable readable.
+    #[inline]
+    pub const fn mxr(self) -> bool {
+        self.bit(Self::MXR)
+    }
+
+    #[inline]
+    pub const fn set_mxr(&mut self, mxr: bool) {
+        self.set_bit(Self::MXR, mxr);
+    }
+
+    /// Trap virtual memory: `satp` accesses and `sfence.vma` in supervisor mode are illegal.
+    #[inline]
+    pub const fn tvm(self) -> bool {
+        self.bit(Self::TVM)
+    }
+
+    #[inline]
+    pub const fn set_tvm(&mut self, tvm: bool) {
+        self.set_bit(Self::TVM, tvm);
+    }
+
+    /// Timeout wait: `wfi` in lower privilege levels traps.
+    #[inline]
+    pub const fn tw(self) -> bool {
+        self.bit(Self::TW)
+    }
+
+    #[inline]
+    pub const fn set_tw(&mut self, tw: bool) {
+        self.set_bit(Self::TW, tw);
+    }
+
+    /// Trap `sret`.
+    #[inline]
+    pub const fn tsr(self) -> bool {
+        self.bit(Self::TSR)
+    }
+
+    #[inline]
+    pub const fn set_tsr(&mut self, tsr: bool) {
+        self.set_bit(Self::TSR, tsr);
+    }
+
+    /// Effective XLEN of user mode.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn uxl(self) -> XLEN {
+        XLEN::try_from((self.0 >> Self::UXL) & 0b11).unwrap_or_default()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_uxl(&mut self, uxl: XLEN) {
+        self.0 = (self.0 & !(0b11 << Self::UXL)) | ((uxl as usize) << Self::UXL);
+    }
+
+    /// Effective XLEN of supervisor mode.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn sxl(self) -> XLEN {
+        XLEN::try_from((self.0 >> Self::SXL) & 0b11).unwrap_or_default()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_sxl(&mut self, sxl: XLEN) {
+        self.0 = (self.0 & !(0b11 << Self::SXL)) | ((sxl as usize) << Self::SXL);
+    }
+
+    /// Supervisor-mode memory accesses are big-endian.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn sbe(self) -> bool {
+        self.bit(Self::SBE)
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_sbe(&mut self, sbe: bool) {
+        self.set_bit(Self::SBE, sbe);
+    }
+
+    /// Machine-mode memory accesses are big-endian.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn mbe(self) -> bool {
+        self.bit(Self::MBE)
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_mbe(&mut self, mbe: bool) {
+        self.set_bit(Self::MBE, mbe);
+    }
+
+    /// Some extension state is dirty.
+    #[inline]
+    pub const fn sd(self) -> bool {
+        self.bit(Self::SD)
+    }
+}
+
+impl From<usize> for Mstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Mstatus> for usize {
+    #[inline]
+    fn from(mstatus: Mstatus) -> Self {
+        mstatus.0
+    }
+}
+
+/// Upper half of the machine status register on RV32.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(target_pointer_width = "32")]
+pub struct Mstatush(usize);
+
+#[cfg(target_pointer_width = "32")]
+impl Mstatush {
+    const SBE: usize = 1 << 4;
+    const MBE: usize = 1 << 5;
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Supervisor-mode memory accesses are big-endian.
+    #[inline]
+    pub const fn sbe(self) -> bool {
+        self.0 & Self::SBE != 0
+    }
+
+    #[inline]
+    pub const fn set_sbe(&mut self, sbe: bool) {
+        if sbe { self.0 |= Self::SBE } else { self.0 &= !Self::SBE }
+    }
+
+    /// Machine-mode memory accesses are big-endian.
+    #[inline]
+    pub const fn mbe(self) -> bool {
+        self.0 & Self::MBE != 0
+    }
+
+    #[inline]
+    pub const fn set_mbe(&mut self, mbe: bool) {
+        if mbe { self.0 |= Self::MBE } else { self.0 &= !Self::MBE }
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl From<usize> for Mstatush {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+impl From<Mstatush> for usize {
+    #[inline]
+    fn from(mstatush: Mstatush) -> Self {
+        mstatush.0
+    }
+}
@@ -0,0 +1,20 @@
+use super::csr;
+use super::imsic::{Identity, InterruptFile, Machine};
+
+csr! {
+    /// Machine top external interrupt register of the interrupt file.
+    pub struct Mtopei: usize = 0x35C;
+}
+
+/// Highest-priority pending and enabled external interrupt, without claiming it.
+#[inline]
+#[must_use]
+pub fn read() -> Option<Identity> {
+    Machine::top()
+}
+
+/// Claim the highest-priority pending and enabled external interrupt, clearing its pending bit.
+#[inline]
+pub unsafe fn claim() -> Option<Identity> {
+    unsafe { Machine::claim() }
+}
@@ -0,0 +1,14 @@
+use super::stopi::Topi;
+use super::{Csr, csr};
+
+csr! {
+    /// Machine top interrupt register.
+    pub struct Mtopi: usize = 0xFB0;
+}
+
+/// Highest-priority pending and enabled machine-level interrupt.
+#[inline]
+#[must_use]
+pub fn read() -> Option<Topi> {
+    Topi::new(Mtopi::read())
+}
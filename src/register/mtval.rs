@@ -0,0 +1,22 @@
+use super::scause::Cause;
+use super::stval::Tval;
+use super::{Csr, csr};
+use crate::address::Virtual;
+
+csr! {
+    /// Machine trap value register.
+    pub struct Mtval: usize = 0x343;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Option<Virtual> {
+    Virtual::new(Mtval::read())
+}
+
+/// Read `mtval` and interpret it according to the `cause` of the trap.
+#[inline]
+#[must_use]
+pub fn read_for(cause: Cause) -> Tval {
+    Tval::new(cause, Mtval::read())
+}
@@ -1,10 +1,22 @@
-#[inline]
-#[must_use]
-pub fn read() -> usize {
-    let data: usize;
-    unsafe { core::arch::asm!("csrr {}, mtvec", out(reg) data, options(nomem, nostack)) };
-    data
+use super::stvec::{self, Error};
+use super::{Csr, csr};
+
+csr! {
+    /// Machine trap-handler base address register.
+    pub struct Mtvec: usize = 0x305;
 }
 
-#[inline]
-pub fn write() {}
+/// Machine trap vector mode.
+pub type Mode = stvec::Mode<extern "riscv-interrupt-m" fn()>;
+
+/// Read the machine trap vector base address register.
+#[inline(always)]
+pub fn read() -> Result<Mode, Error> {
+    Mode::try_from(Mtvec::read())
+}
+
+/// Write to the machine trap vector base address register.
+#[inline(always)]
+pub unsafe fn write(mode: Mode) {
+    unsafe { Mtvec::write(mode.into()) };
+}
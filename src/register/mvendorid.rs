@@ -1,3 +1,67 @@
+use super::{Csr, csr};
+use core::num::NonZero;
+
+csr! {
+    /// Machine vendor ID register.
+    pub struct Mvendorid: usize = 0xF11;
+}
+
+/// Read the JEDEC vendor ID, or [`None`] for non-commercial implementations.
 #[inline]
 #[must_use]
-pub fn read() {}
\ No newline at end of file
+pub fn read() -> Option<VendorId> {
+    NonZero::new(Mvendorid::read() as u32).map(VendorId)
+}
+
+/// JEDEC manufacturer ID of the hart's provider.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VendorId(NonZero<u32>);
+
+impl VendorId {
+    #[inline]
+    pub const fn get(self) -> u32 {
+        self.0.get()
+    }
+
+    /// Number of `0x7F` continuation codes preceding the final byte, i.e. the JEDEC bank minus one.
+    #[inline]
+    pub const fn bank(self) -> u32 {
+        self.get() >> 7
+    }
+
+    /// Final byte of the JEDEC ID with the parity bit stripped.
+    #[inline]
+    pub const fn offset(self) -> u8 {
+        (self.get() & 0x7F) as u8
+    }
+
+    /// Known vendor, if any.
+    #[inline]
+    pub fn vendor(self) -> Option<Vendor> {
+        Vendor::try_from(self.get()).ok()
+    }
+}
+
+/// Known RISC-V vendors.
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vendor {
+    Andes = 0x31E,
+    SiFive = 0x489,
+    THead = 0x5B7,
+}
+
+impl TryFrom<u32> for Vendor {
+    type Error = ();
+
+    fn try_from(vendor: u32) -> Result<Self, Self::Error> {
+        Ok(match vendor {
+            0x31E => Self::Andes,
+            0x489 => Self::SiFive,
+            0x5B7 => Self::THead,
+            _ => return Err(()),
+        })
+    }
+}
@@ -0,0 +1,373 @@
+use super::Csr;
+
+/// Number of PMP configuration fields packed in each `pmpcfg` register.
+const PER_CFG: usize = usize::BITS as usize / 8;
+
+/// Physical memory protection configuration register `pmpcfgN`.
+///
+/// On RV64 only the even-numbered registers exist.
+#[derive(Debug, Clone, Copy)]
+pub struct Pmpcfg<const N: usize>;
+
+impl<const N: usize> Csr for Pmpcfg<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 16, "pmpcfg index out of range");
+        assert!(PER_CFG == 4 || N.is_multiple_of(2), "odd pmpcfg registers do not exist on RV64");
+        0x3A0 + N as u16
+    };
+    type Value = usize;
+}
+
+/// Physical memory protection address register `pmpaddrN`.
+#[derive(Debug, Clone, Copy)]
+pub struct Pmpaddr<const N: usize>;
+
+impl<const N: usize> Csr for Pmpaddr<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 64, "pmpaddr index out of range");
+        0x3B0 + N as u16
+    };
+    type Value = usize;
+}
+
+/// Run `$body` with `$csr` bound to the `pmpaddr` register of a runtime `$index`.
+#[rustfmt::skip]
+macro with_pmpaddr($index:expr, |$csr:ident| $body:expr) {
+    match $index {
+        0 => { type $csr = Pmpaddr<0>; $body }
+        1 => { type $csr = Pmpaddr<1>; $body }
+        2 => { type $csr = Pmpaddr<2>; $body }
+        3 => { type $csr = Pmpaddr<3>; $body }
+        4 => { type $csr = Pmpaddr<4>; $body }
+        5 => { type $csr = Pmpaddr<5>; $body }
+        6 => { type $csr = Pmpaddr<6>; $body }
+        7 => { type $csr = Pmpaddr<7>; $body }
+        8 => { type $csr = Pmpaddr<8>; $body }
+        9 => { type $csr = Pmpaddr<9>; $body }
+        10 => { type $csr = Pmpaddr<10>; $body }
+        11 => { type $csr = Pmpaddr<11>; $body }
+        12 => { type $csr = Pmpaddr<12>; $body }
+        13 => { type $csr = Pmpaddr<13>; $body }
+        14 => { type $csr = Pmpaddr<14>; $body }
+        15 => { type $csr = Pmpaddr<15>; $body }
+        16 => { type $csr = Pmpaddr<16>; $body }
+        17 => { type $csr = Pmpaddr<17>; $body }
+        18 => { type $csr = Pmpaddr<18>; $body }
+        19 => { type $csr = Pmpaddr<19>; $body }
+        20 => { type $csr = Pmpaddr<20>; $body }
+        21 => { type $csr = Pmpaddr<21>; $body }
+        22 => { type $csr = Pmpaddr<22>; $body }
+        23 => { type $csr = Pmpaddr<23>; $body }
+        24 => { type $csr = Pmpaddr<24>; $body }
+        25 => { type $csr = Pmpaddr<25>; $body }
+        26 => { type $csr = Pmpaddr<26>; $body }
+        27 => { type $csr = Pmpaddr<27>; $body }
+        28 => { type $csr = Pmpaddr<28>; $body }
+        29 => { type $csr = Pmpaddr<29>; $body }
+        30 => { type $csr = Pmpaddr<30>; $body }
+        31 => { type $csr = Pmpaddr<31>; $body }
+        32 => { type $csr = Pmpaddr<32>; $body }
+        33 => { type $csr = Pmpaddr<33>; $body }
+        34 => { type $csr = Pmpaddr<34>; $body }
+        35 => { type $csr = Pmpaddr<35>; $body }
+        36 => { type $csr = Pmpaddr<36>; $body }
+        37 => { type $csr = Pmpaddr<37>; $body }
+        38 => { type $csr = Pmpaddr<38>; $body }
+        39 => { type $csr = Pmpaddr<39>; $body }
+        40 => { type $csr = Pmpaddr<40>; $body }
+        41 => { type $csr = Pmpaddr<41>; $body }
+        42 => { type $csr = Pmpaddr<42>; $body }
+        43 => { type $csr = Pmpaddr<43>; $body }
+        44 => { type $csr = Pmpaddr<44>; $body }
+        45 => { type $csr = Pmpaddr<45>; $body }
+        46 => { type $csr = Pmpaddr<46>; $body }
+        47 => { type $csr = Pmpaddr<47>; $body }
+        48 => { type $csr = Pmpaddr<48>; $body }
+        49 => { type $csr = Pmpaddr<49>; $body }
+        50 => { type $csr = Pmpaddr<50>; $body }
+        51 => { type $csr = Pmpaddr<51>; $body }
+        52 => { type $csr = Pmpaddr<52>; $body }
+        53 => { type $csr = Pmpaddr<53>; $body }
+        54 => { type $csr = Pmpaddr<54>; $body }
+        55 => { type $csr = Pmpaddr<55>; $body }
+        56 => { type $csr = Pmpaddr<56>; $body }
+        57 => { type $csr = Pmpaddr<57>; $body }
+        58 => { type $csr = Pmpaddr<58>; $body }
+        59 => { type $csr = Pmpaddr<59>; $body }
+        60 => { type $csr = Pmpaddr<60>; $body }
+        61 => { type $csr = Pmpaddr<61>; $body }
+        62 => { type $csr = Pmpaddr<62>; $body }
+        63 => { type $csr = Pmpaddr<63>; $body }
+        index => panic!("PMP entry {index} out of range"),
+    }
+}
+
+/// Run `$body` with `$csr` bound to the `pmpcfg` register holding the configuration of entry `$index`.
+#[rustfmt::skip]
+#[cfg(target_pointer_width = "32")]
+macro with_pmpcfg($index:expr, |$csr:ident| $body:expr) {
+    match $index / PER_CFG {
+        0 => { type $csr = Pmpcfg<0>; $body }
+        1 => { type $csr = Pmpcfg<1>; $body }
+        2 => { type $csr = Pmpcfg<2>; $body }
+        3 => { type $csr = Pmpcfg<3>; $body }
+        4 => { type $csr = Pmpcfg<4>; $body }
+        5 => { type $csr = Pmpcfg<5>; $body }
+        6 => { type $csr = Pmpcfg<6>; $body }
+        7 => { type $csr = Pmpcfg<7>; $body }
+        8 => { type $csr = Pmpcfg<8>; $body }
+        9 => { type $csr = Pmpcfg<9>; $body }
+        10 => { type $csr = Pmpcfg<10>; $body }
+        11 => { type $csr = Pmpcfg<11>; $body }
+        12 => { type $csr = Pmpcfg<12>; $body }
+        13 => { type $csr = Pmpcfg<13>; $body }
+        14 => { type $csr = Pmpcfg<14>; $body }
+        15 => { type $csr = Pmpcfg<15>; $body }
+        _ => panic!("PMP entry {} out of range", $index),
+    }
+}
+
+/// Run `$body` with `$csr` bound to the `pmpcfg` register holding the configuration of entry `$index`.
+#[rustfmt::skip]
+#[cfg(target_pointer_width = "64")]
+macro with_pmpcfg($index:expr, |$csr:ident| $body:expr) {
+    match $index / PER_CFG {
+        0 => { type $csr = Pmpcfg<0>; $body }
+        1 => { type $csr = Pmpcfg<2>; $body }
+        2 => { type $csr = Pmpcfg<4>; $body }
+        3 => { type $csr = Pmpcfg<6>; $body }
+        4 => { type $csr = Pmpcfg<8>; $body }
+        5 => { type $csr = Pmpcfg<10>; $body }
+        6 => { type $csr = Pmpcfg<12>; $body }
+        7 => { type $csr = Pmpcfg<14>; $body }
+        _ => panic!("PMP entry {} out of range", $index),
+    }
+}
+
+/// Read the configuration of PMP entry `index`.
+#[inline]
+#[must_use]
+pub fn read_entry(index: usize) -> PmpEntry {
+    let shift = (index % PER_CFG) * 8;
+    with_pmpcfg!(index, |Cfg| PmpEntry((Cfg::read() >> shift) as u8))
+}
+
+/// Read the raw `pmpaddr` value of PMP entry `index`.
+#[inline]
+#[must_use]
+pub fn read_address(index: usize) -> usize {
+    with_pmpaddr!(index, |Addr| Addr::read())
+}
+
+/// Program PMP entry `index` to cover `region` with the permissions of `entry`.
+///
+/// The address is written before the configuration, so a locked entry never guards a stale range. Writes to an entry
+/// that is already locked are ignored by the hart.
+#[inline]
+pub unsafe fn write(index: usize, region: Region, entry: PmpEntry) {
+    let shift = (index % PER_CFG) * 8;
+    let entry = entry.with_matching(region.matching);
+
+    unsafe {
+        with_pmpaddr!(index, |Addr| Addr::write(region.address));
+        with_pmpcfg!(index, |Cfg| {
+            Cfg::clear(0xFF << shift);
+            Cfg::set((entry.0 as usize) << shift);
+        });
+    }
+}
+
+/// Turn PMP entry `index` off.
+#[inline]
+pub unsafe fn disable(index: usize) {
+    let shift = (index % PER_CFG) * 8;
+    unsafe { with_pmpcfg!(index, |Cfg| Cfg::clear(0xFF << shift)) };
+}
+
+/// Discover the PMP granularity in bytes, or [`None`] if no PMP entries are implemented.
+///
+/// Follows the probing sequence from the privileged specification on entry 0, which must not be locked. Its previous
+/// state is restored afterwards. Without PMP, `pmpaddr0` is read-only zero and the probe reads back `0`.
+#[inline]
+pub unsafe fn granularity() -> Option<usize> {
+    unsafe {
+        let entry = read_entry(0);
+        let address = read_address(0);
+
+        disable(0);
+        Pmpaddr::<0>::write(usize::MAX);
+        let probed = Pmpaddr::<0>::read();
+
+        Pmpaddr::<0>::write(address);
+        Pmpcfg::<0>::set(entry.0 as usize);
+
+        if probed == 0 {
+            return None;
+        }
+        Some(1 << (probed.trailing_zeros() + 2))
+    }
+}
+
+/// Configuration of a single PMP entry.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PmpEntry(u8);
+
+impl PmpEntry {
+    const R: u8 = 1 << 0;
+    const W: u8 = 1 << 1;
+    const X: u8 = 1 << 2;
+    const A: u8 = 3;
+    const L: u8 = 1 << 7;
+
+    /// Entry granting no permissions.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: u8, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    #[inline]
+    pub const fn readable(self) -> bool {
+        self.0 & Self::R != 0
+    }
+
+    #[inline]
+    pub const fn with_readable(self, readable: bool) -> Self {
+        self.with_bit(Self::R, readable)
+    }
+
+    #[inline]
+    pub const fn writable(self) -> bool {
+        self.0 & Self::W != 0
+    }
+
+    #[inline]
+    pub const fn with_writable(self, writable: bool) -> Self {
+        self.with_bit(Self::W, writable)
+    }
+
+    #[inline]
+    pub const fn executable(self) -> bool {
+        self.0 & Self::X != 0
+    }
+
+    #[inline]
+    pub const fn with_executable(self, executable: bool) -> Self {
+        self.with_bit(Self::X, executable)
+    }
+
+    /// Locked entries also apply to machine mode and cannot be modified until reset.
+    #[inline]
+    pub const fn locked(self) -> bool {
+        self.0 & Self::L != 0
+    }
+
+    #[inline]
+    pub const fn with_locked(self, locked: bool) -> Self {
+        self.with_bit(Self::L, locked)
+    }
+
+    #[inline]
+    pub const fn matching(self) -> Matching {
+        match (self.0 >> Self::A) & 0b11 {
+            0 => Matching::Off,
+            1 => Matching::Tor,
+            2 => Matching::Na4,
+            _ => Matching::Napot,
+        }
+    }
+
+    #[inline]
+    pub const fn with_matching(self, matching: Matching) -> Self {
+        Self((self.0 & !(0b11 << Self::A)) | ((matching as u8) << Self::A))
+    }
+}
+
+impl Default for PmpEntry {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Address-matching mode of a PMP entry.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Matching {
+    /// Null region.
+    Off = 0,
+    /// Top of range: matches from the previous entry's address up to this one.
+    Tor = 1,
+    /// Naturally aligned four-byte region.
+    Na4 = 2,
+    /// Naturally aligned power-of-two region, eight bytes or larger.
+    Napot = 3,
+}
+
+/// Address range matched by a PMP entry, encoded for `pmpaddr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    address: usize,
+    matching: Matching,
+}
+
+impl Region {
+    /// Range from the previous entry's address (or zero for entry 0) up to, but excluding, `top`.
+    #[inline]
+    pub const fn tor(top: usize) -> Result<Self, Error> {
+        if !top.is_multiple_of(4) {
+            return Err(Error::Misaligned(top));
+        }
+        Ok(Self { address: top >> 2, matching: Matching::Tor })
+    }
+
+    /// Four bytes at `base`.
+    #[inline]
+    pub const fn na4(base: usize) -> Result<Self, Error> {
+        if !base.is_multiple_of(4) {
+            return Err(Error::Misaligned(base));
+        }
+        Ok(Self { address: base >> 2, matching: Matching::Na4 })
+    }
+
+    /// `size` bytes at `base`. `size` must be a power of two of at least eight and `base` aligned to it.
+    #[inline]
+    pub const fn napot(base: usize, size: usize) -> Result<Self, Error> {
+        if !size.is_power_of_two() || size < 8 {
+            return Err(Error::InvalidSize(size));
+        }
+        if !base.is_multiple_of(size) {
+            return Err(Error::Misaligned(base));
+        }
+        Ok(Self { address: (base | (size / 2 - 1)) >> 2, matching: Matching::Napot })
+    }
+
+    /// Encoded `pmpaddr` value.
+    #[inline]
+    pub const fn address(self) -> usize {
+        self.address
+    }
+
+    #[inline]
+    pub const fn matching(self) -> Matching {
+        self.matching
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("PMP region address {0:#x} is not sufficiently aligned")]
+    Misaligned(usize),
+    #[error("invalid NAPOT region size: {0:#x}")]
+    InvalidSize(usize),
+}
@@ -0,0 +1,191 @@
+use super::mhpmevent::Event;
+use super::scause::Interrupt;
+use super::siselect::{Sireg, Sireg2, Siselect};
+#[cfg(target_pointer_width = "32")]
+use super::siselect::{Sireg4, Sireg5};
+use super::{Csr, scountovf, sepc, sie, sip};
+
+/// Statistical PC sampler driven by counter-overflow interrupts of `hpmcounterN`.
+///
+/// Runs in supervisor mode and programs the counter through the Smcdeleg `siselect` window. Machine mode must first
+/// set `menvcfg.CDE`, set bit `N` of `mcounteren` and delegate [`Interrupt::CounterOverflow`] through `mideleg`.
+///
+/// Each access goes through `siselect`. The previous selection is restored afterwards, so [`Pmu::handle`] does not
+/// disturb an interrupted `siselect`/`sireg` sequence, such as an IMSIC access or another [`Pmu::start`].
+#[derive(Debug, Clone, Copy)]
+pub struct Pmu<const N: usize> {
+    event: Event,
+    period: u64,
+}
+
+impl<const N: usize> Pmu<N> {
+    const SELECT: usize = {
+        assert!(N >= 3 && N <= 31, "hpm counter index out of range");
+        0x40 + N
+    };
+
+    /// Sample once every `period` occurrences of `event`. A zero period is treated as one.
+    #[inline]
+    pub const fn new(event: Event, period: u64) -> Self {
+        Self { event: event.with_of(false), period: if period == 0 { 1 } else { period } }
+    }
+
+    #[inline]
+    pub const fn event(self) -> Event {
+        self.event
+    }
+
+    #[inline]
+    pub const fn period(self) -> u64 {
+        self.period
+    }
+
+    /// Program the counter and enable the local counter-overflow interrupt.
+    #[inline]
+    pub unsafe fn start(&self) {
+        unsafe {
+            let select = Siselect::swap(Self::SELECT);
+            write_event(Event::new(0));
+            self.rearm();
+            write_event(self.event);
+            Siselect::write(select);
+            sie::enable(Interrupt::CounterOverflow);
+        }
+    }
+
+    /// Stop counting. The interrupt stays enabled, since other counters may share it.
+    #[inline]
+    pub unsafe fn stop(&self) {
+        unsafe {
+            let select = Siselect::swap(Self::SELECT);
+            write_event(Event::new(0));
+            Siselect::write(select);
+        }
+    }
+
+    /// Handle a counter-overflow interrupt, recording the interrupted `sepc` into `samples`.
+    ///
+    /// Returns `false` without side effects if this counter has not overflowed, so several samplers can share the
+    /// interrupt. The pending bit is cleared, then raised again if any delegated counter is still overflowed, so an
+    /// overflow landing in between is not lost.
+    #[inline]
+    pub unsafe fn handle(&self, samples: &mut Samples<'_>) -> bool {
+        if !scountovf::read().hpm(N) {
+            return false;
+        }
+
+        samples.push(sepc::read());
+
+        unsafe {
+            let select = Siselect::swap(Self::SELECT);
+            self.rearm();
+            write_event(self.event);
+            Siselect::write(select);
+        }
+
+        sip::clear_counter_overflow();
+        if !scountovf::read().is_empty() {
+            sip::set_counter_overflow();
+        }
+        true
+    }
+
+    /// Load the counter so that it overflows after `period` more events.
+    #[inline]
+    unsafe fn rearm(&self) {
+        let count = self.period.wrapping_neg();
+
+        #[cfg(target_pointer_width = "64")]
+        unsafe {
+            Sireg::write(count as usize);
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        unsafe {
+            Sireg::write(0);
+            Sireg4::write((count >> 32) as usize);
+            Sireg::write(count as usize);
+        }
+    }
+}
+
+/// Write the event selector through the window currently selected in `siselect`.
+#[inline]
+unsafe fn write_event(event: Event) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Sireg2::write(event.bits() as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Sireg2::write(event.bits() as usize);
+        Sireg5::write((event.bits() >> 32) as usize);
+    }
+}
+
+/// Caller-provided buffer of sampled program counters.
+///
+/// Samples arriving once the buffer is full are counted but not stored.
+#[derive(Debug)]
+pub struct Samples<'a> {
+    buffer: &'a mut [usize],
+    len: usize,
+    dropped: usize,
+}
+
+impl<'a> Samples<'a> {
+    #[inline]
+    pub const fn new(buffer: &'a mut [usize]) -> Self {
+        Self { buffer, len: 0, dropped: 0 }
+    }
+
+    /// Record `pc`, returning whether there was room for it.
+    #[inline]
+    pub fn push(&mut self, pc: usize) -> bool {
+        match self.buffer.get_mut(self.len) {
+            Some(slot) => {
+                *slot = pc;
+                self.len += 1;
+                true
+            }
+            None => {
+                self.dropped += 1;
+                false
+            }
+        }
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[usize] {
+        &self.buffer[..self.len]
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == self.buffer.len()
+    }
+
+    /// Number of samples lost because the buffer was full.
+    #[inline]
+    pub const fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// Discard the recorded samples and the dropped count.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.dropped = 0;
+    }
+}
@@ -0,0 +1,181 @@
+use super::{Csr, csr};
+use crate::sfence_vma_all;
+
+csr! {
+    impl Satp = 0x180;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Satp {
+    Satp::read()
+}
+
+#[inline]
+pub unsafe fn write(satp: Satp) {
+    unsafe { Satp::write(satp) };
+}
+
+/// Switch to the address space described by `satp`.
+///
+/// Fences before the write so that prior page-table stores are visible to the walker, and after it so that no stale
+/// translations of a recycled ASID survive.
+#[inline]
+pub unsafe fn activate(satp: Satp) {
+    sfence_vma_all();
+    unsafe { write(satp) };
+    sfence_vma_all();
+}
+
+/// Check whether the hart implements the translation `mode`.
+///
+/// `satp` is WARL: a write with an unsupported mode has no effect. The previous value is restored afterwards.
+///
+/// Must be run in machine mode with `mstatus.MPRV` clear, where `satp` does not affect the running code.
+#[inline]
+pub unsafe fn probe(mode: Mode) -> bool {
+    unsafe {
+        let satp = Satp::swap(Satp::new(mode, 0, 0));
+        let supported = read().mode() == Some(mode);
+        write(satp);
+        supported
+    }
+}
+
+/// Supervisor address translation and protection register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Satp(usize);
+
+impl Satp {
+    #[cfg(target_pointer_width = "32")]
+    const MODE: usize = 31;
+    #[cfg(target_pointer_width = "32")]
+    const ASID: usize = 22;
+    #[cfg(target_pointer_width = "32")]
+    const ASID_MASK: usize = 0x1FF;
+    #[cfg(target_pointer_width = "32")]
+    const PPN_MASK: usize = 0x3F_FFFF;
+
+    #[cfg(target_pointer_width = "64")]
+    const MODE: usize = 60;
+    #[cfg(target_pointer_width = "64")]
+    const ASID: usize = 44;
+    #[cfg(target_pointer_width = "64")]
+    const ASID_MASK: usize = 0xFFFF;
+    #[cfg(target_pointer_width = "64")]
+    const PPN_MASK: usize = 0xFFF_FFFF_FFFF;
+
+    /// Build a `satp` value. Out-of-range `asid` and `ppn` bits are truncated.
+    #[inline]
+    pub const fn new(mode: Mode, asid: usize, ppn: usize) -> Self {
+        Self(((mode as usize) << Self::MODE) | ((asid & Self::ASID_MASK) << Self::ASID) | (ppn & Self::PPN_MASK))
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Address translation scheme, or [`None`] for a reserved encoding.
+    #[inline]
+    pub fn mode(self) -> Option<Mode> {
+        Mode::try_from(self.0 >> Self::MODE).ok()
+    }
+
+    #[inline]
+    pub const fn set_mode(&mut self, mode: Mode) {
+        self.0 = (self.0 & ((1 << Self::MODE) - 1)) | ((mode as usize) << Self::MODE);
+    }
+
+    /// Address space identifier.
+    #[inline]
+    pub const fn asid(self) -> usize {
+        (self.0 >> Self::ASID) & Self::ASID_MASK
+    }
+
+    #[inline]
+    pub const fn set_asid(&mut self, asid: usize) {
+        self.0 = (self.0 & !(Self::ASID_MASK << Self::ASID)) | ((asid & Self::ASID_MASK) << Self::ASID);
+    }
+
+    /// Physical page number of the root page table.
+    #[inline]
+    pub const fn ppn(self) -> usize {
+        self.0 & Self::PPN_MASK
+    }
+
+    #[inline]
+    pub const fn set_ppn(&mut self, ppn: usize) {
+        self.0 = (self.0 & !Self::PPN_MASK) | (ppn & Self::PPN_MASK);
+    }
+}
+
+impl From<usize> for Satp {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Satp> for usize {
+    #[inline]
+    fn from(satp: Satp) -> Self {
+        satp.0
+    }
+}
+
+/// Address translation scheme.
+#[repr(usize)]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// No translation or protection.
+    Bare = 0,
+    /// Page-based 32-bit virtual addressing.
+    #[cfg(target_pointer_width = "32")]
+    Sv32 = 1,
+    /// Page-based 39-bit virtual addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv39 = 8,
+    /// Page-based 48-bit virtual addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv48 = 9,
+    /// Page-based 57-bit virtual addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv57 = 10,
+    /// Reserved for page-based 64-bit virtual addressing.
+    #[cfg(target_pointer_width = "64")]
+    Sv64 = 11,
+}
+
+impl Mode {
+    /// All modes encodable on the current XLEN.
+    #[cfg(target_pointer_width = "32")]
+    pub const ALL: [Self; 2] = [Self::Bare, Self::Sv32];
+
+    /// All modes encodable on the current XLEN.
+    #[cfg(target_pointer_width = "64")]
+    pub const ALL: [Self; 5] = [Self::Bare, Self::Sv39, Self::Sv48, Self::Sv57, Self::Sv64];
+}
+
+impl TryFrom<usize> for Mode {
+    type Error = ();
+
+    fn try_from(mode: usize) -> Result<Self, Self::Error> {
+        Ok(match mode {
+            0 => Self::Bare,
+            #[cfg(target_pointer_width = "32")]
+            1 => Self::Sv32,
+            #[cfg(target_pointer_width = "64")]
+            8 => Self::Sv39,
+            #[cfg(target_pointer_width = "64")]
+            9 => Self::Sv48,
+            #[cfg(target_pointer_width = "64")]
+            10 => Self::Sv57,
+            #[cfg(target_pointer_width = "64")]
+            11 => Self::Sv64,
+            _ => return Err(()),
+        })
+    }
+}
@@ -1,17 +1,14 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor trap cause register.
+    pub struct Scause: usize = 0x142;
+}
+
 #[inline]
 #[must_use]
 pub fn read() -> Cause {
-    let scause: usize;
-    unsafe { core::arch::asm!("csrr {}, scause", out(reg) scause, options(nomem, nostack)) };
-
-    let is_interrupt = (scause >> (core::mem::size_of::<usize>() * 8 - 1)) != 0;
-    let code = scause & !(1 << (core::mem::size_of::<usize>() * 8 - 1));
-
-    if is_interrupt {
-        unsafe { Cause::Interrupt(Interrupt::try_from(code).unwrap_unchecked()) }
-    } else {
-        unsafe { Cause::Exception(Exception::try_from(code).unwrap_unchecked()) }
-    }
+    Cause::from_bits(Scause::read())
 }
 
 #[non_exhaustive]
@@ -19,6 +16,36 @@ pub fn read() -> Cause {
 pub enum Cause {
     Exception(Exception),
     Interrupt(Interrupt),
+    /// Exception code designated for custom use, 24–31 and 48–63.
+    Custom(usize),
+    /// Interrupt code designated for platform use, 16 and above.
+    Platform(usize),
+    /// Reserved encoding, holding the raw register value including the interrupt bit.
+    Reserved(usize),
+}
+
+impl Cause {
+    const INTERRUPT: usize = 1 << (usize::BITS - 1);
+
+    /// Decode the value of `scause`, `mcause` or `vscause`.
+    #[inline]
+    pub fn from_bits(cause: usize) -> Self {
+        let code = cause & !Self::INTERRUPT;
+
+        if cause & Self::INTERRUPT != 0 {
+            match Interrupt::try_from(code) {
+                Ok(interrupt) => Self::Interrupt(interrupt),
+                Err(()) if code >= 16 => Self::Platform(code),
+                Err(()) => Self::Reserved(cause),
+            }
+        } else {
+            match Exception::try_from(code) {
+                Ok(exception) => Self::Exception(exception),
+                Err(()) if matches!(code, 24..=31 | 48..=63) => Self::Custom(code),
+                Err(()) => Self::Reserved(cause),
+            }
+        }
+    }
 }
 
 #[repr(usize)]
@@ -35,9 +62,52 @@ pub enum Exception {
     StoreAccessFault = 7,
     UserEnvironmentCall = 8,
     SupervisorEnvironmentCall = 9,
+    VirtualSupervisorEnvironmentCall = 10,
+    MachineEnvironmentCall = 11,
     InstructionPageFault = 12,
     LoadPageFault = 13,
     StorePageFault = 15,
+    DoubleTrap = 16,
+    SoftwareCheck = 18,
+    HardwareError = 19,
+    InstructionGuestPageFault = 20,
+    LoadGuestPageFault = 21,
+    VirtualInstruction = 22,
+    StoreGuestPageFault = 23,
+}
+
+impl Exception {
+    /// All exceptions, in ascending code order.
+    pub const ALL: [Self; 22] = [
+        Self::InstructionAddressMisaligned,
+        Self::InstructionAccessFault,
+        Self::IllegalInstruction,
+        Self::Breakpoint,
+        Self::LoadAddressMisaligned,
+        Self::LoadAccessFault,
+        Self::StoreAddressMisaligned,
+        Self::StoreAccessFault,
+        Self::UserEnvironmentCall,
+        Self::SupervisorEnvironmentCall,
+        Self::VirtualSupervisorEnvironmentCall,
+        Self::MachineEnvironmentCall,
+        Self::InstructionPageFault,
+        Self::LoadPageFault,
+        Self::StorePageFault,
+        Self::DoubleTrap,
+        Self::SoftwareCheck,
+        Self::HardwareError,
+        Self::InstructionGuestPageFault,
+        Self::LoadGuestPageFault,
+        Self::VirtualInstruction,
+        Self::StoreGuestPageFault,
+    ];
+
+    /// Bit of the exception in `medeleg` and `hedeleg`.
+    #[inline]
+    pub const fn mask(self) -> usize {
+        1 << self as usize
+    }
 }
 
 impl TryFrom<usize> for Exception {
@@ -55,9 +125,18 @@ impl TryFrom<usize> for Exception {
             7 => Self::StoreAccessFault,
             8 => Self::UserEnvironmentCall,
             9 => Self::SupervisorEnvironmentCall,
+            10 => Self::VirtualSupervisorEnvironmentCall,
+            11 => Self::MachineEnvironmentCall,
             12 => Self::InstructionPageFault,
             13 => Self::LoadPageFault,
             15 => Self::StorePageFault,
+            16 => Self::DoubleTrap,
+            18 => Self::SoftwareCheck,
+            19 => Self::HardwareError,
+            20 => Self::InstructionGuestPageFault,
+            21 => Self::LoadGuestPageFault,
+            22 => Self::VirtualInstruction,
+            23 => Self::StoreGuestPageFault,
             _ => return Err(()),
         })
     }
@@ -68,8 +147,39 @@ impl TryFrom<usize> for Exception {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Interrupt {
     Software = 1,
+    VirtualSupervisorSoftware = 2,
+    MachineSoftware = 3,
     Timer = 5,
+    VirtualSupervisorTimer = 6,
+    MachineTimer = 7,
     External = 9,
+    VirtualSupervisorExternal = 10,
+    MachineExternal = 11,
+    SupervisorGuestExternal = 12,
+    CounterOverflow = 13,
+}
+
+impl Interrupt {
+    /// All interrupts, in ascending code order.
+    pub const ALL: [Self; 11] = [
+        Self::Software,
+        Self::VirtualSupervisorSoftware,
+        Self::MachineSoftware,
+        Self::Timer,
+        Self::VirtualSupervisorTimer,
+        Self::MachineTimer,
+        Self::External,
+        Self::VirtualSupervisorExternal,
+        Self::MachineExternal,
+        Self::SupervisorGuestExternal,
+        Self::CounterOverflow,
+    ];
+
+    /// Bit of the interrupt in the interrupt-enable, interrupt-pending and delegation registers.
+    #[inline]
+    pub const fn mask(self) -> usize {
+        1 << self as usize
+    }
 }
 
 impl TryFrom<usize> for Interrupt {
@@ -78,8 +188,16 @@ impl TryFrom<usize> for Interrupt {
     fn try_from(interrupt: usize) -> Result<Self, Self::Error> {
         Ok(match interrupt {
             1 => Self::Software,
+            2 => Self::VirtualSupervisorSoftware,
+            3 => Self::MachineSoftware,
             5 => Self::Timer,
+            6 => Self::VirtualSupervisorTimer,
+            7 => Self::MachineTimer,
             9 => Self::External,
+            10 => Self::VirtualSupervisorExternal,
+            11 => Self::MachineExternal,
+            12 => Self::SupervisorGuestExternal,
+            13 => Self::CounterOverflow,
             _ => return Err(()),
         })
     }
@@ -0,0 +1,121 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor counter-enable register.
+    pub struct Scounteren: Counteren = 0x106;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Counteren {
+    Scounteren::read()
+}
+
+#[inline]
+pub unsafe fn write(scounteren: Counteren) {
+    unsafe { Scounteren::write(scounteren) };
+}
+
+/// Discover which counters can be exposed to user mode; the remaining bits are hardwired to zero.
+///
+/// Temporarily enables every counter for user mode. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn probe() -> Counteren {
+    unsafe {
+        let scounteren = Scounteren::swap(Counteren::all());
+        let implemented = read();
+        write(scounteren);
+        implemented
+    }
+}
+
+/// Counters accessible from the next-lower privilege level.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counteren(u32);
+
+impl Counteren {
+    const CY: u32 = 1 << 0;
+    const TM: u32 = 1 << 1;
+    const IR: u32 = 1 << 2;
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn all() -> Self {
+        Self(u32::MAX)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: u32, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    /// `cycle` is accessible.
+    #[inline]
+    pub const fn cy(self) -> bool {
+        self.0 & Self::CY != 0
+    }
+
+    #[inline]
+    pub const fn with_cy(self, cy: bool) -> Self {
+        self.with_bit(Self::CY, cy)
+    }
+
+    /// `time` is accessible.
+    #[inline]
+    pub const fn tm(self) -> bool {
+        self.0 & Self::TM != 0
+    }
+
+    #[inline]
+    pub const fn with_tm(self, tm: bool) -> Self {
+        self.with_bit(Self::TM, tm)
+    }
+
+    /// `instret` is accessible.
+    #[inline]
+    pub const fn ir(self) -> bool {
+        self.0 & Self::IR != 0
+    }
+
+    #[inline]
+    pub const fn with_ir(self, ir: bool) -> Self {
+        self.with_bit(Self::IR, ir)
+    }
+
+    /// `hpmcounter{n}` is accessible.
+    #[inline]
+    pub const fn hpm(self, n: usize) -> bool {
+        assert!(n >= 3 && n <= 31, "hpm counter index out of range");
+        self.0 & (1 << n) != 0
+    }
+
+    #[inline]
+    pub const fn with_hpm(self, n: usize, hpm: bool) -> Self {
+        assert!(n >= 3 && n <= 31, "hpm counter index out of range");
+        self.with_bit(1 << n, hpm)
+    }
+}
+
+impl From<usize> for Counteren {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u32)
+    }
+}
+
+impl From<Counteren> for usize {
+    #[inline]
+    fn from(counteren: Counteren) -> Self {
+        counteren.0 as usize
+    }
+}
@@ -0,0 +1,50 @@
+use super::{Csr, csr};
+
+csr! {
+    impl Scountovf = 0xDA0;
+}
+
+/// Read the overflow flags of the counters supervisor mode may access.
+#[inline]
+#[must_use]
+pub fn read() -> Scountovf {
+    Scountovf::read()
+}
+
+/// Supervisor count overflow register, a read-only view of the `mhpmeventN.OF` bits enabled in `mcounteren`.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scountovf(u32);
+
+impl Scountovf {
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// `hpmcounter{n}` has overflowed.
+    #[inline]
+    pub const fn hpm(self, n: usize) -> bool {
+        assert!(n >= 3 && n <= 31, "hpm counter index out of range");
+        self.0 & (1 << n) != 0
+    }
+}
+
+impl From<usize> for Scountovf {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u32)
+    }
+}
+
+impl From<Scountovf> for usize {
+    #[inline]
+    fn from(scountovf: Scountovf) -> Self {
+        scountovf.0 as usize
+    }
+}
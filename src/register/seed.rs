@@ -0,0 +1,181 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Entropy source register. Only accessible with a read-write instruction such as `csrrw`.
+    pub struct Seed: usize = 0x015;
+}
+
+/// Poll the entropy source.
+///
+/// Outside machine mode this traps unless enabled by `mseccfg.SSEED` or `mseccfg.USEED`.
+#[inline]
+#[must_use]
+pub fn read() -> Status {
+    Status::from(unsafe { Seed::swap(0) })
+}
+
+/// Number of raw samples mixed into each seed: 512 bits of input for 256 bits of output.
+const SAMPLES: usize = 32;
+
+/// Collect raw samples and condition them into a 256-bit seed with SHA-256.
+///
+/// Spins while the source is self-testing or waiting for entropy.
+#[inline]
+pub fn condition() -> Result<[u8; 32], Error> {
+    let mut block = [0; 64];
+    let mut samples = 0;
+
+    while samples < SAMPLES {
+        match read() {
+            Status::Es16(entropy) => {
+                block[samples * 2..samples * 2 + 2].copy_from_slice(&entropy.to_be_bytes());
+                samples += 1;
+            }
+            Status::Bist | Status::Wait => core::hint::spin_loop(),
+            Status::Dead => return Err(Error::Dead),
+        }
+    }
+
+    Ok(sha256::digest(&block))
+}
+
+/// State of the entropy source, with the sample when one is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Built-in self-test in progress.
+    Bist,
+    /// Not enough entropy has accumulated yet.
+    Wait,
+    /// 16 bits of raw entropy. Must be conditioned before use as a key or seed.
+    Es16(u16),
+    /// Unrecoverable self-test failure.
+    Dead,
+}
+
+impl From<usize> for Status {
+    #[inline]
+    fn from(seed: usize) -> Self {
+        match (seed >> 30) & 0b11 {
+            0b00 => Self::Bist,
+            0b01 => Self::Wait,
+            0b10 => Self::Es16(seed as u16),
+            _ => Self::Dead,
+        }
+    }
+}
+
+/// Random number generator drawing conditioned seeds from the entropy source.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Entropy;
+
+impl Entropy {
+    /// Fill `dest` with conditioned entropy.
+    #[inline]
+    pub fn fill(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        for chunk in dest.chunks_mut(32) {
+            chunk.copy_from_slice(&condition()?[..chunk.len()]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand_core")]
+#[doc(cfg(feature = "rand_core"))]
+impl rand_core::TryRngCore for Entropy {
+    type Error = Error;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0; 4];
+        self.fill(&mut bytes)?;
+        Ok(u32::from_ne_bytes(bytes))
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0; 8];
+        self.fill(&mut bytes)?;
+        Ok(u64::from_ne_bytes(bytes))
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        self.fill(dest)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+#[doc(cfg(feature = "rand_core"))]
+impl rand_core::TryCryptoRng for Entropy {}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("entropy source failed its self-test")]
+    Dead,
+}
+
+mod sha256 {
+    const K: [u32; 64] = [
+        0x428A2F98, 0x71374491, 0xB5C0FBCF, 0xE9B5DBA5, 0x3956C25B, 0x59F111F1, 0x923F82A4, 0xAB1C5ED5, 0xD807AA98, 0x12835B01,
+        0x243185BE, 0x550C7DC3, 0x72BE5D74, 0x80DEB1FE, 0x9BDC06A7, 0xC19BF174, 0xE49B69C1, 0xEFBE4786, 0x0FC19DC6, 0x240CA1CC,
+        0x2DE92C6F, 0x4A7484AA, 0x5CB0A9DC, 0x76F988DA, 0x983E5152, 0xA831C66D, 0xB00327C8, 0xBF597FC7, 0xC6E00BF3, 0xD5A79147,
+        0x06CA6351, 0x14292967, 0x27B70A85, 0x2E1B2138, 0x4D2C6DFC, 0x53380D13, 0x650A7354, 0x766A0ABB, 0x81C2C92E, 0x92722C85,
+        0xA2BFE8A1, 0xA81A664B, 0xC24B8B70, 0xC76C51A3, 0xD192E819, 0xD6990624, 0xF40E3585, 0x106AA070, 0x19A4C116, 0x1E376C08,
+        0x2748774C, 0x34B0BCB5, 0x391C0CB3, 0x4ED8AA4A, 0x5B9CCA4F, 0x682E6FF3, 0x748F82EE, 0x78A5636F, 0x84C87814, 0x8CC70208,
+        0x90BEFFFA, 0xA4506CEB, 0xBEF9A3F7, 0xC67178F2,
+    ];
+
+    const H: [u32; 8] = [0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19];
+
+    /// SHA-256 of a single 64-byte message.
+    pub(super) fn digest(message: &[u8; 64]) -> [u8; 32] {
+        let mut padding = [0; 64];
+        padding[0] = 0x80;
+        padding[56..].copy_from_slice(&512u64.to_be_bytes());
+
+        let mut state = H;
+        compress(&mut state, message);
+        compress(&mut state, &padding);
+
+        let mut digest = [0; 32];
+        for (bytes, word) in digest.as_chunks_mut::<4>().0.iter_mut().zip(state) {
+            *bytes = word.to_be_bytes();
+        }
+        digest
+    }
+
+    fn compress(state: &mut [u32; 8], block: &[u8; 64]) {
+        let mut w = [0; 64];
+        for (word, bytes) in w.iter_mut().zip(block.as_chunks::<4>().0) {
+            *word = u32::from_be_bytes(*bytes);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(K[i]).wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+        }
+
+        for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *word = word.wrapping_add(value);
+        }
+    }
+}
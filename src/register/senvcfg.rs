@@ -0,0 +1,172 @@
+use super::menvcfg::CacheBlockInvalidate;
+use super::{Csr, csr};
+#[cfg(target_pointer_width = "64")]
+use crate::PointerMasking;
+
+csr! {
+    impl Senvcfg = 0x10A;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Senvcfg {
+    Senvcfg::read()
+}
+
+#[inline]
+pub unsafe fn write(senvcfg: Senvcfg) {
+    unsafe { Senvcfg::write(senvcfg) };
+}
+
+#[inline]
+pub unsafe fn update(f: impl FnOnce(Senvcfg) -> Senvcfg) {
+    unsafe { Senvcfg::modify(f) };
+}
+
+/// Check whether the hart implements pointer masking `pmm` for user mode.
+///
+/// The field is WARL: an unsupported length reads back differently. The previous value is restored afterwards.
+#[inline]
+#[cfg(target_pointer_width = "64")]
+pub unsafe fn probe(pmm: PointerMasking) -> bool {
+    unsafe {
+        let senvcfg = read();
+        let mut probe = senvcfg;
+        probe.set_pmm(pmm);
+        write(probe);
+        let supported = read().pmm() == Some(pmm);
+        write(senvcfg);
+        supported
+    }
+}
+
+/// Supervisor environment configuration register. Controls the execution environment of user mode.
+///
+/// Fields left disabled by `menvcfg` are read-only zero.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Senvcfg(usize);
+
+impl Senvcfg {
+    const FIOM: usize = 1 << 0;
+    const LPE: usize = 1 << 2;
+    const SSE: usize = 1 << 3;
+    const CBIE: usize = 4;
+    const CBCFE: usize = 1 << 6;
+    const CBZE: usize = 1 << 7;
+    #[cfg(target_pointer_width = "64")]
+    const PMM: usize = 32;
+
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: usize) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: usize, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Fence of I/O implies memory: `fence` on I/O in user mode also orders memory accesses.
+    #[inline]
+    pub const fn fiom(self) -> bool {
+        self.bit(Self::FIOM)
+    }
+
+    #[inline]
+    pub const fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(Self::FIOM, fiom);
+    }
+
+    /// Landing pads are enforced in user mode.
+    #[inline]
+    pub const fn lpe(self) -> bool {
+        self.bit(Self::LPE)
+    }
+
+    #[inline]
+    pub const fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(Self::LPE, lpe);
+    }
+
+    /// Shadow stacks are active in user mode.
+    #[inline]
+    pub const fn sse(self) -> bool {
+        self.bit(Self::SSE)
+    }
+
+    #[inline]
+    pub const fn set_sse(&mut self, sse: bool) {
+        self.set_bit(Self::SSE, sse);
+    }
+
+    /// Behaviour of `cbo.inval` in user mode, or [`None`] for the reserved encoding.
+    #[inline]
+    pub fn cbie(self) -> Option<CacheBlockInvalidate> {
+        CacheBlockInvalidate::try_from((self.0 >> Self::CBIE) & 0b11).ok()
+    }
+
+    #[inline]
+    pub const fn set_cbie(&mut self, cbie: CacheBlockInvalidate) {
+        self.0 = (self.0 & !(0b11 << Self::CBIE)) | ((cbie as usize) << Self::CBIE);
+    }
+
+    /// `cbo.clean` and `cbo.flush` are allowed in user mode.
+    #[inline]
+    pub const fn cbcfe(self) -> bool {
+        self.bit(Self::CBCFE)
+    }
+
+    #[inline]
+    pub const fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(Self::CBCFE, cbcfe);
+    }
+
+    /// `cbo.zero` is allowed in user mode.
+    #[inline]
+    pub const fn cbze(self) -> bool {
+        self.bit(Self::CBZE)
+    }
+
+    #[inline]
+    pub const fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(Self::CBZE, cbze);
+    }
+
+    /// Pointer masking for user mode, or [`None`] for the reserved encoding.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn pmm(self) -> Option<PointerMasking> {
+        PointerMasking::try_from((self.0 >> Self::PMM) & 0b11).ok()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_pmm(&mut self, pmm: PointerMasking) {
+        self.0 = (self.0 & !(0b11 << Self::PMM)) | ((pmm as usize) << Self::PMM);
+    }
+}
+
+impl From<usize> for Senvcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Senvcfg> for usize {
+    #[inline]
+    fn from(senvcfg: Senvcfg) -> Self {
+        senvcfg.0
+    }
+}
@@ -1,14 +1,19 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor exception program counter.
+    pub struct Sepc: usize = 0x141;
+}
+
 #[inline]
 #[must_use]
 pub fn read() -> usize {
-    let sepc: usize;
-    unsafe { core::arch::asm!("csrr {}, sepc", out(reg) sepc, options(nomem, nostack)) };
-    sepc
+    Sepc::read()
 }
 
 #[inline]
 pub unsafe fn write(sepc: usize) {
-    unsafe { core::arch::asm!("csrw sepc, {}", in(reg) sepc & !0b11, options(nomem, nostack)) };
+    unsafe { Sepc::write(sepc & !0b1) };
 }
 
 #[inline]
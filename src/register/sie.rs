@@ -1 +1,88 @@
-pub unsafe fn write() {}
\ No newline at end of file
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    impl Sie = 0x104;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Sie {
+    Sie::read()
+}
+
+#[inline]
+pub unsafe fn write(sie: Sie) {
+    unsafe { Sie::write(sie) };
+}
+
+/// Enable a supervisor interrupt source.
+#[inline]
+pub unsafe fn enable(interrupt: Interrupt) {
+    unsafe { Sie::set(Sie(interrupt.mask())) };
+}
+
+/// Disable a supervisor interrupt source.
+#[inline]
+pub unsafe fn disable(interrupt: Interrupt) {
+    unsafe { Sie::clear(Sie(interrupt.mask())) };
+}
+
+/// Supervisor interrupt-enable register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sie(usize);
+
+impl Sie {
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_enabled(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    #[inline]
+    pub const fn enable(&mut self, interrupt: Interrupt) {
+        self.0 |= interrupt.mask();
+    }
+
+    #[inline]
+    pub const fn disable(&mut self, interrupt: Interrupt) {
+        self.0 &= !interrupt.mask();
+    }
+
+    /// Iterate over the enabled interrupt sources.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.into_iter().filter(move |&interrupt| self.is_enabled(interrupt))
+    }
+}
+
+impl FromIterator<Interrupt> for Sie {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Interrupt>>(iter: I) -> Self {
+        Self(iter.into_iter().fold(0, |bits, interrupt| bits | interrupt.mask()))
+    }
+}
+
+impl From<usize> for Sie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Sie> for usize {
+    #[inline]
+    fn from(sie: Sie) -> Self {
+        sie.0
+    }
+}
@@ -0,0 +1,73 @@
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    impl Sip = 0x144;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Sip {
+    Sip::read()
+}
+
+#[inline]
+#[must_use]
+pub fn is_pending(interrupt: Interrupt) -> bool {
+    read().is_pending(interrupt)
+}
+
+/// Clear a pending supervisor software interrupt.
+#[inline]
+pub fn clear_software() {
+    unsafe { Sip::clear_const::<{ Interrupt::Software.mask() }>() };
+}
+
+/// Clear a pending local counter-overflow interrupt.
+#[inline]
+pub fn clear_counter_overflow() {
+    unsafe { Sip::clear_const::<{ Interrupt::CounterOverflow.mask() }>() };
+}
+
+/// Mark a local counter-overflow interrupt as pending.
+#[inline]
+pub fn set_counter_overflow() {
+    unsafe { Sip::set_const::<{ Interrupt::CounterOverflow.mask() }>() };
+}
+
+/// Supervisor interrupt-pending register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sip(usize);
+
+impl Sip {
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_pending(self, interrupt: Interrupt) -> bool {
+        self.0 & interrupt.mask() != 0
+    }
+
+    /// Iterate over the pending interrupt sources, lowest code first.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Interrupt> {
+        Interrupt::ALL.into_iter().filter(move |&interrupt| self.is_pending(interrupt))
+    }
+}
+
+impl From<usize> for Sip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Sip> for usize {
+    #[inline]
+    fn from(sip: Sip) -> Self {
+        sip.0
+    }
+}
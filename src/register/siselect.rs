@@ -0,0 +1,42 @@
+use super::{Indirect, csr};
+
+csr! {
+    /// Supervisor indirect register select.
+    pub struct Siselect: usize = 0x150;
+}
+
+csr! {
+    /// Supervisor indirect register alias, accessing the register selected by `siselect`.
+    pub struct Sireg: usize = 0x151;
+}
+
+csr! {
+    /// Second supervisor indirect register alias.
+    pub struct Sireg2: usize = 0x152;
+}
+
+csr! {
+    /// Fourth supervisor indirect register alias, holding the upper half of 64-bit registers on RV32.
+    pub struct Sireg4: usize = 0x155;
+}
+
+csr! {
+    /// Fifth supervisor indirect register alias, holding the upper half of 64-bit `sireg2` registers on RV32.
+    pub struct Sireg5: usize = 0x156;
+}
+
+impl Indirect for Siselect {
+    type Alias = Sireg;
+}
+
+/// Read the supervisor-level register selected by `select`.
+#[inline]
+pub unsafe fn read(select: usize) -> usize {
+    unsafe { Siselect::read_indirect(select) }
+}
+
+/// Write the supervisor-level register selected by `select`.
+#[inline]
+pub unsafe fn write(select: usize, value: usize) {
+    unsafe { Siselect::write_indirect(select, value) };
+}
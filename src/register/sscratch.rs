@@ -1,14 +1,22 @@
-use core::arch::asm;
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor scratch register.
+    pub struct Sscratch: usize = 0x140;
+}
 
 #[must_use]
 #[inline]
 pub fn read() -> usize {
-    let data: usize;
-    unsafe { asm!("csrr {}, sscratch", out(reg) data, options(nomem, nostack)) };
-    data
+    Sscratch::read()
 }
 
 #[inline]
 pub fn write(data: usize) {
-    unsafe { asm!("csrw sscratch, {}", in(reg) data, options(nomem, nostack)) };
+    unsafe { Sscratch::write(data) };
+}
+
+#[inline]
+pub fn swap(data: usize) -> usize {
+    unsafe { Sscratch::swap(data) }
 }
@@ -0,0 +1,89 @@
+use super::{Csr, csr, senvcfg};
+
+csr! {
+    /// Shadow stack pointer.
+    pub struct Ssp: usize = 0x011;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Ssp::read()
+}
+
+#[inline]
+pub unsafe fn write(ssp: usize) {
+    unsafe { Ssp::write(ssp) };
+}
+
+const PAGE_SIZE: usize = 4096;
+
+/// Validated address range of a user thread's shadow stack.
+///
+/// This only checks and arms a range; allocating it and mapping it as shadow stack pages (`XWR = 010`) is left to the
+/// caller's page table code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShadowStackRegion {
+    base: usize,
+    size: usize,
+}
+
+impl ShadowStackRegion {
+    /// Shadow stack occupying the `size` bytes at `base`.
+    ///
+    /// The range must be page aligned and must not wrap around the end of the address space. It must be mapped in the
+    /// thread's address space as shadow stack pages before the thread runs. Ordinary stores cannot reach such pages.
+    #[inline]
+    pub const fn new(base: usize, size: usize) -> Result<Self, Error> {
+        if !base.is_multiple_of(PAGE_SIZE) {
+            return Err(Error::Misaligned(base));
+        }
+        if size == 0 || !size.is_multiple_of(PAGE_SIZE) {
+            return Err(Error::InvalidSize(size));
+        }
+        if base.checked_add(size).is_none() {
+            return Err(Error::OutOfRange(base, size));
+        }
+        Ok(Self { base, size })
+    }
+
+    #[inline]
+    pub const fn base(self) -> usize {
+        self.base
+    }
+
+    #[inline]
+    pub const fn size(self) -> usize {
+        self.size
+    }
+
+    /// Initial shadow stack pointer of a new thread. The shadow stack grows downwards.
+    #[inline]
+    pub const fn top(self) -> usize {
+        self.base + self.size
+    }
+
+    /// Point `ssp` at the empty shadow stack and activate shadow stacks for user mode.
+    ///
+    /// Called before first entering a new thread. Requires `menvcfg.SSE` to be set by machine mode.
+    #[inline]
+    pub unsafe fn arm(self) {
+        unsafe {
+            write(self.top());
+            senvcfg::update(|mut senvcfg| {
+                senvcfg.set_sse(true);
+                senvcfg
+            });
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("shadow stack base {0:#x} is not page aligned")]
+    Misaligned(usize),
+    #[error("invalid shadow stack size: {0:#x}")]
+    InvalidSize(usize),
+    #[error("shadow stack at {0:#x} with size {1:#x} wraps around the address space")]
+    OutOfRange(usize, usize),
+}
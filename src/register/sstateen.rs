@@ -0,0 +1,39 @@
+use super::{Csr, Stateen};
+
+/// Supervisor state-enable register `sstateenN`. Only the low 32 bits are defined.
+#[derive(Debug, Clone, Copy)]
+pub struct Sstateen<const N: usize>;
+
+impl<const N: usize> Csr for Sstateen<N> {
+    const ADDRESS: u16 = {
+        assert!(N < 4, "sstateen index out of range");
+        0x10C + N as u16
+    };
+    type Value = usize;
+}
+
+#[inline]
+#[must_use]
+pub fn read<const N: usize>() -> Stateen {
+    Stateen::from_bits(Sstateen::<N>::read() as u32 as u64)
+}
+
+/// Grant user mode access to the state guarded by the bits set in `stateen`, and deny it everything else.
+#[inline]
+pub unsafe fn write<const N: usize>(stateen: Stateen) {
+    unsafe { Sstateen::<N>::write(stateen.bits() as u32 as usize) };
+}
+
+/// Discover which bits of `sstateenN` the hart implements.
+///
+/// All bits are WARL, so writing all ones reads back as the implemented set. The previous value is restored afterwards.
+#[inline]
+pub unsafe fn implemented<const N: usize>() -> Stateen {
+    unsafe {
+        let stateen = read::<N>();
+        write::<N>(Stateen::all());
+        let implemented = read::<N>();
+        write::<N>(stateen);
+        implemented
+    }
+}
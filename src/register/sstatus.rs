@@ -1,12 +1,233 @@
+use super::{Csr, csr};
+use crate::Privilege;
+#[cfg(target_pointer_width = "64")]
+use crate::XLEN;
+
+csr! {
+    impl Sstatus = 0x100;
+}
+
 #[inline]
 #[must_use]
-pub fn read() -> usize {
-    let bits: usize;
-    unsafe { core::arch::asm!("csrr {}, sstatus", out(reg) bits, options(nomem, nostack)) };
-    bits
+pub fn read() -> Sstatus {
+    Sstatus::read()
 }
 
 #[inline]
-pub unsafe fn write(bits: usize) {
-    unsafe { core::arch::asm!("csrw sstatus, {}", in(reg) bits, options(nomem, nostack)) };
+pub unsafe fn write(sstatus: Sstatus) {
+    unsafe { Sstatus::write(sstatus) };
+}
+
+/// Enable supervisor interrupts.
+#[inline]
+pub unsafe fn set_sie() {
+    unsafe { Sstatus::set_const::<{ Sstatus::SIE }>() };
+}
+
+/// Disable supervisor interrupts.
+#[inline]
+pub unsafe fn clear_sie() {
+    unsafe { Sstatus::clear_const::<{ Sstatus::SIE }>() };
+}
+
+/// Permit supervisor access to user memory.
+#[inline]
+pub unsafe fn set_sum() {
+    unsafe { Sstatus::set_const::<{ Sstatus::SUM }>() };
+}
+
+/// Forbid supervisor access to user memory.
+#[inline]
+pub unsafe fn clear_sum() {
+    unsafe { Sstatus::clear_const::<{ Sstatus::SUM }>() };
+}
+
+/// Supervisor status register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sstatus(usize);
+
+impl Sstatus {
+    const SIE: usize = 1 << 1;
+    const SPIE: usize = 1 << 5;
+    const UBE: usize = 1 << 6;
+    const SPP: usize = 1 << 8;
+    const VS: usize = 9;
+    const FS: usize = 13;
+    const XS: usize = 15;
+    const SUM: usize = 1 << 18;
+    const MXR: usize = 1 << 19;
+    #[cfg(target_pointer_width = "64")]
+    const UXL: usize = 32;
+    const SD: usize = 1 << (usize::BITS - 1);
+
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn bit(self, mask: usize) -> bool {
+        self.0 & mask != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, mask: usize, value: bool) {
+        if value { self.0 |= mask } else { self.0 &= !mask }
+    }
+
+    /// Supervisor interrupt enable.
+    #[inline]
+    pub const fn sie(self) -> bool {
+        self.bit(Self::SIE)
+    }
+
+    #[inline]
+    pub const fn set_sie(&mut self, sie: bool) {
+        self.set_bit(Self::SIE, sie);
+    }
+
+    /// Supervisor interrupt enable prior to the trap.
+    #[inline]
+    pub const fn spie(self) -> bool {
+        self.bit(Self::SPIE)
+    }
+
+    #[inline]
+    pub const fn set_spie(&mut self, spie: bool) {
+        self.set_bit(Self::SPIE, spie);
+    }
+
+    /// User-mode memory accesses are big-endian.
+    #[inline]
+    pub const fn ube(self) -> bool {
+        self.bit(Self::UBE)
+    }
+
+    #[inline]
+    pub const fn set_ube(&mut self, ube: bool) {
+        self.set_bit(Self::UBE, ube);
+    }
+
+    /// Privilege level the hart was in prior to the trap.
+    #[inline]
+    pub const fn spp(self) -> Privilege {
+        if self.bit(Self::SPP) { Privilege::Supervisor } else { Privilege::User }
+    }
+
+    /// Set the privilege level `sret` returns to. Anything above user mode is encoded as supervisor mode.
+    #[inline]
+    pub const fn set_spp(&mut self, spp: Privilege) {
+        self.set_bit(Self::SPP, !matches!(spp, Privilege::User));
+    }
+
+    /// Vector extension state.
+    #[inline]
+    pub const fn vs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::VS)
+    }
+
+    #[inline]
+    pub const fn set_vs(&mut self, vs: ExtensionState) {
+        self.0 = (self.0 & !(0b11 << Self::VS)) | ((vs as usize) << Self::VS);
+    }
+
+    /// Floating-point unit state.
+    #[inline]
+    pub const fn fs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::FS)
+    }
+
+    #[inline]
+    pub const fn set_fs(&mut self, fs: ExtensionState) {
+        self.0 = (self.0 & !(0b11 << Self::FS)) | ((fs as usize) << Self::FS);
+    }
+
+    /// Summary of additional user-mode extension state.
+    #[inline]
+    pub const fn xs(self) -> ExtensionState {
+        ExtensionState::from_bits(self.0 >> Self::XS)
+    }
+
+    /// Permit supervisor user memory access.
+    #[inline]
+    pub const fn sum(self) -> bool {
+        self.bit(Self::SUM)
+    }
+
+    #[inline]
+    pub const fn set_sum(&mut self, sum: bool) {
+        self.set_bit(Self::SUM, sum);
+    }
+
+    /// Make executable readable.
+    #[inline]
+    pub const fn mxr(self) -> bool {
+        self.bit(Self::MXR)
+    }
+
+    #[inline]
+    pub const fn set_mxr(&mut self, mxr: bool) {
+        self.set_bit(Self::MXR, mxr);
+    }
+
+    /// Effective XLEN of user mode.
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub fn uxl(self) -> XLEN {
+        XLEN::try_from((self.0 >> Self::UXL) & 0b11).unwrap_or_default()
+    }
+
+    #[inline]
+    #[cfg(target_pointer_width = "64")]
+    pub const fn set_uxl(&mut self, uxl: XLEN) {
+        self.0 = (self.0 & !(0b11 << Self::UXL)) | ((uxl as usize) << Self::UXL);
+    }
+
+    /// Some extension state is dirty.
+    #[inline]
+    pub const fn sd(self) -> bool {
+        self.bit(Self::SD)
+    }
+}
+
+impl From<usize> for Sstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Sstatus> for usize {
+    #[inline]
+    fn from(sstatus: Sstatus) -> Self {
+        sstatus.0
+    }
+}
+
+/// Context status of an extension.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExtensionState {
+    Off = 0,
+    Initial = 1,
+    Clean = 2,
+    Dirty = 3,
+}
+
+impl ExtensionState {
+    #[inline]
+    pub(crate) const fn from_bits(bits: usize) -> Self {
+        match bits & 0b11 {
+            0 => Self::Off,
+            1 => Self::Initial,
+            2 => Self::Clean,
+            _ => Self::Dirty,
+        }
+    }
 }
@@ -0,0 +1,120 @@
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+
+/// State-enable bits of an `mstateenN`, `hstateenN` or `sstateenN` register.
+///
+/// A clear bit makes the guarded state inaccessible to the next lower privilege level. Apart from [`Stateen::SE`], the
+/// named bits are only defined in the registers with index 0.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stateen(u64);
+
+impl Stateen {
+    /// Custom state of nonstandard extensions.
+    pub const C: Self = Self(1 << 0);
+    /// `fcsr` under Zfinx, where it is not already guarded by `mstatus.FS`.
+    pub const FCSR: Self = Self(1 << 1);
+    /// `jvt` of the Zcmt extension.
+    pub const JVT: Self = Self(1 << 2);
+    /// `srmcfg` of the Ssqosid extension.
+    pub const SRMCFG: Self = Self(1 << 55);
+    /// `hedelegh`, added in version 1.13 of the privileged specification.
+    pub const P1P13: Self = Self(1 << 56);
+    /// `scontext` and `hcontext` of the Sdtrig extension.
+    pub const CONTEXT: Self = Self(1 << 57);
+    /// IMSIC state: `stopei` and the interrupt file behind `siselect`.
+    pub const IMSIC: Self = Self(1 << 58);
+    /// Other AIA state: `stopi`, `siph`, `sieh` and the priority registers behind `siselect`.
+    pub const AIA: Self = Self(1 << 59);
+    /// `siselect` and `sireg`.
+    pub const CSRIND: Self = Self(1 << 60);
+    /// `senvcfg` and `henvcfg`.
+    pub const ENVCFG: Self = Self(1 << 62);
+    /// The state-enable register of the same index at the next lower privilege level.
+    pub const SE: Self = Self(1 << 63);
+
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Every bit, including those not yet assigned.
+    #[inline]
+    pub const fn all() -> Self {
+        Self(u64::MAX)
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl From<u64> for Stateen {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Stateen> for u64 {
+    #[inline]
+    fn from(stateen: Stateen) -> Self {
+        stateen.0
+    }
+}
+
+impl BitOr for Stateen {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Stateen {
+    #[inline(always)]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for Stateen {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for Stateen {
+    #[inline(always)]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Not for Stateen {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
@@ -0,0 +1,110 @@
+use super::scause::Interrupt;
+use super::{Csr, csr, sie, sip, time};
+use core::time::Duration;
+
+csr! {
+    /// Supervisor timer compare register.
+    pub struct Stimecmp: usize = 0x14D;
+}
+
+#[cfg(target_pointer_width = "32")]
+csr! {
+    /// Upper 32 bits of the supervisor timer compare register on RV32.
+    pub struct Stimecmph: usize = 0x15D;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    #[cfg(target_pointer_width = "64")]
+    {
+        Stimecmp::read() as u64
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        ((Stimecmph::read() as u64) << 32) | (Stimecmp::read() as u64)
+    }
+}
+
+/// Set the `time` value at which the supervisor timer interrupt becomes pending.
+///
+/// On RV32 the low half is first set to all ones, so no intermediate value can be earlier than both the old and the new
+/// deadline and raise a spurious interrupt.
+#[inline]
+pub unsafe fn write(stimecmp: u64) {
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        Stimecmp::write(stimecmp as usize);
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        Stimecmp::write(usize::MAX);
+        Stimecmph::write((stimecmp >> 32) as usize);
+        Stimecmp::write(stimecmp as usize);
+    }
+}
+
+/// Supervisor timer driven by `stimecmp`.
+#[derive(Debug, Clone, Copy)]
+pub struct Timer {
+    frequency: u64,
+}
+
+impl Timer {
+    /// Timer whose `time` counter runs at `frequency` Hz, as reported by the platform.
+    #[inline]
+    pub const fn new(frequency: u64) -> Self {
+        Self { frequency }
+    }
+
+    #[inline]
+    pub const fn frequency(self) -> u64 {
+        self.frequency
+    }
+
+    /// Fire when `time` reaches `deadline`.
+    #[inline]
+    pub unsafe fn arm_at(self, deadline: u64) {
+        unsafe { write(deadline) };
+    }
+
+    /// Fire `ticks` of `time` from now.
+    #[inline]
+    pub unsafe fn arm_ticks(self, ticks: u64) {
+        unsafe { self.arm_at(time::read().saturating_add(ticks)) };
+    }
+
+    /// Fire `duration` from now.
+    #[inline]
+    pub unsafe fn arm(self, duration: Duration) {
+        let ticks = duration
+            .as_nanos()
+            .checked_mul(self.frequency as u128)
+            .map_or(u64::MAX, |ticks| u64::try_from(ticks / 1_000_000_000).unwrap_or(u64::MAX));
+        unsafe { self.arm_ticks(ticks) };
+    }
+
+    /// Push the deadline to the end of time, which also clears a pending timer interrupt.
+    #[inline]
+    pub unsafe fn cancel(self) {
+        unsafe { write(u64::MAX) };
+    }
+
+    /// Deliver [`Interrupt::Timer`] when the deadline passes.
+    #[inline]
+    pub unsafe fn enable(self) {
+        unsafe { sie::enable(Interrupt::Timer) };
+    }
+
+    #[inline]
+    pub unsafe fn disable(self) {
+        unsafe { sie::disable(Interrupt::Timer) };
+    }
+
+    #[inline]
+    pub fn is_pending(self) -> bool {
+        sip::is_pending(Interrupt::Timer)
+    }
+}
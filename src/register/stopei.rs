@@ -0,0 +1,20 @@
+use super::csr;
+use super::imsic::{Identity, InterruptFile, Supervisor};
+
+csr! {
+    /// Supervisor top external interrupt register of the interrupt file.
+    pub struct Stopei: usize = 0x15C;
+}
+
+/// Highest-priority pending and enabled external interrupt, without claiming it.
+#[inline]
+#[must_use]
+pub fn read() -> Option<Identity> {
+    Supervisor::top()
+}
+
+/// Claim the highest-priority pending and enabled external interrupt, clearing its pending bit.
+#[inline]
+pub unsafe fn claim() -> Option<Identity> {
+    unsafe { Supervisor::claim() }
+}
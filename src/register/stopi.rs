@@ -0,0 +1,54 @@
+use super::scause::Interrupt;
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor top interrupt register.
+    pub struct Stopi: usize = 0xDB0;
+}
+
+/// Highest-priority pending and enabled supervisor-level interrupt.
+#[inline]
+#[must_use]
+pub fn read() -> Option<Topi> {
+    Topi::new(Stopi::read())
+}
+
+/// Top pending interrupt and its priority.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Topi(usize);
+
+impl Topi {
+    const IID: usize = 16;
+    const IID_MASK: usize = 0xFFF;
+    const IPRIO_MASK: usize = 0xFF;
+
+    /// Decode a `stopi` or `mtopi` value, or [`None`] if no interrupt is pending.
+    #[inline]
+    pub const fn new(bits: usize) -> Option<Self> {
+        if bits == 0 { None } else { Some(Self(bits)) }
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Major interrupt number, as it would be reported in the cause register.
+    #[inline]
+    pub const fn iid(self) -> usize {
+        (self.0 >> Self::IID) & Self::IID_MASK
+    }
+
+    /// Standard interrupt, or [`None`] for a platform or reserved interrupt number.
+    #[inline]
+    pub fn interrupt(self) -> Option<Interrupt> {
+        Interrupt::try_from(self.iid()).ok()
+    }
+
+    /// Priority of the interrupt. Lower values take precedence and zero means the default order.
+    #[inline]
+    pub const fn priority(self) -> u8 {
+        (self.0 & Self::IPRIO_MASK) as u8
+    }
+}
@@ -1,10 +1,89 @@
+use super::scause::{Cause, Exception};
+use super::{Csr, csr};
 use crate::address::Virtual;
-use core::arch::asm;
+
+csr! {
+    /// Supervisor trap value register.
+    pub struct Stval: usize = 0x143;
+}
 
 #[inline]
 #[must_use]
 pub fn read() -> Option<Virtual> {
-    let val: usize;
-    unsafe { asm!("csrr {}, stval", out(reg) val, options(nomem, nostack)) };
-    Virtual::new(val)
+    Virtual::new(Stval::read())
+}
+
+/// Read `stval` and interpret it according to the `cause` of the trap.
+#[inline]
+#[must_use]
+pub fn read_for(cause: Cause) -> Tval {
+    Tval::new(cause, Stval::read())
+}
+
+/// Trap value, interpreted according to the cause of the trap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tval {
+    /// Virtual address that caused the fault, or the address of the instruction for a breakpoint. Misaligned accesses
+    /// may report a non-canonical address.
+    ///
+    /// `0` is either a genuine fault at address zero, such as a null-pointer dereference, or a hart that does not
+    /// report faulting addresses; the two cannot be told apart.
+    FaultAddress(usize),
+    /// Bits of the offending instruction. Compressed instructions occupy the low 16 bits.
+    Instruction(u32),
+    /// Kind of control-flow integrity violation that raised a software-check exception.
+    SoftwareCheck(SoftwareCheck),
+    /// The cause does not define a trap value, or the hart did not provide one.
+    None,
+}
+
+impl Tval {
+    /// Interpret the raw trap value `tval` of a trap caused by `cause`.
+    #[inline]
+    pub fn new(cause: Cause, tval: usize) -> Self {
+        let Cause::Exception(exception) = cause else {
+            return Self::None;
+        };
+
+        match exception {
+            Exception::InstructionAddressMisaligned
+            | Exception::InstructionAccessFault
+            | Exception::Breakpoint
+            | Exception::LoadAddressMisaligned
+            | Exception::LoadAccessFault
+            | Exception::StoreAddressMisaligned
+            | Exception::StoreAccessFault
+            | Exception::InstructionPageFault
+            | Exception::LoadPageFault
+            | Exception::StorePageFault
+            | Exception::InstructionGuestPageFault
+            | Exception::LoadGuestPageFault
+            | Exception::StoreGuestPageFault => Self::FaultAddress(tval),
+            Exception::IllegalInstruction | Exception::VirtualInstruction if tval != 0 => Self::Instruction(tval as u32),
+            Exception::SoftwareCheck => SoftwareCheck::try_from(tval).map_or(Self::None, Self::SoftwareCheck),
+            _ => Self::None,
+        }
+    }
+}
+
+/// Software-check exception code, reported in the trap value register.
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftwareCheck {
+    /// An indirect jump or call did not land on a matching `lpad`.
+    LandingPad = 2,
+    /// `sspopchk` or `c.sspopchk` found a mismatching return address.
+    ShadowStack = 3,
+}
+
+impl TryFrom<usize> for SoftwareCheck {
+    type Error = ();
+
+    fn try_from(code: usize) -> Result<Self, Self::Error> {
+        Ok(match code {
+            2 => Self::LandingPad,
+            3 => Self::ShadowStack,
+            _ => return Err(()),
+        })
+    }
 }
@@ -1,52 +1,95 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Supervisor trap vector base address register.
+    pub struct Stvec: usize = 0x105;
+}
+
 // Read the supervisor trap vector base address register.
 #[must_use]
 #[inline(always)]
 pub fn read() -> Result<Mode, Error> {
-    let stvec: usize;
-    unsafe { core::arch::asm!("csrr {}, stvec", out(reg) stvec, options(nomem, nostack)) };
-    Mode::try_from(stvec)
+    Mode::try_from(Stvec::read())
 }
 
 /// Write to the supervisor trap vector base address register.
 #[inline(always)]
 pub unsafe fn write(mode: Mode) {
-    let stvec: usize = mode.into();
-    unsafe { core::arch::asm!("csrw stvec, {}", in(reg) stvec, options(nomem, nostack)) };
+    unsafe { Stvec::write(mode.into()) };
 }
 
+/// Trap vector mode, generic over the ABI of the direct-mode handler.
 #[repr(u8)]
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
-pub enum Mode {
-    Direct(extern "riscv-interrupt-s" fn()) = 0,
+pub enum Mode<H: Handler = extern "riscv-interrupt-s" fn()> {
+    Direct(H) = 0,
     Vectored(*const ()) = 1,
 }
 
-impl From<Mode> for usize {
+impl<H: Handler> From<Mode<H>> for usize {
     #[inline]
-    fn from(mode: Mode) -> Self {
+    fn from(mode: Mode<H>) -> Self {
         match mode {
-            Mode::Direct(handler) => (handler as usize) & !0b11 | 0,
+            Mode::Direct(handler) => handler.address() & !0b11 | 0,
             Mode::Vectored(vector) => ((vector as usize) & !0b11) | 1,
         }
     }
 }
 
-impl TryFrom<usize> for Mode {
+impl<H: Handler> TryFrom<usize> for Mode<H> {
     type Error = Error;
 
     #[inline]
     fn try_from(mode: usize) -> Result<Self, Self::Error> {
         match mode & 0b11 {
-            0 => Ok(Mode::Direct(unsafe { core::mem::transmute((mode & !0b11) as *const ()) })),
+            0 => Ok(Mode::Direct(unsafe { H::from_address(mode & !0b11) })),
             1 => Ok(Mode::Vectored((mode & !0b11) as *const ())),
             mode => Err(Error::Invalid(mode)),
         }
     }
 }
 
+/// Trap handler function pointer.
+pub trait Handler: Copy + sealed::Sealed {
+    fn address(self) -> usize;
+
+    unsafe fn from_address(address: usize) -> Self;
+}
+
+impl Handler for extern "riscv-interrupt-s" fn() {
+    #[inline]
+    fn address(self) -> usize {
+        self as usize
+    }
+
+    #[inline]
+    unsafe fn from_address(address: usize) -> Self {
+        unsafe { core::mem::transmute(address as *const ()) }
+    }
+}
+
+impl Handler for extern "riscv-interrupt-m" fn() {
+    #[inline]
+    fn address(self) -> usize {
+        self as usize
+    }
+
+    #[inline]
+    unsafe fn from_address(address: usize) -> Self {
+        unsafe { core::mem::transmute(address as *const ()) }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+impl sealed::Sealed for extern "riscv-interrupt-s" fn() {}
+impl sealed::Sealed for extern "riscv-interrupt-m" fn() {}
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("invalid or unimplemented stvec mode: {0}")]
+    #[error("invalid or unimplemented trap vector mode: {0}")]
     Invalid(usize),
 }
@@ -0,0 +1,14 @@
+use super::{Counter, csr};
+
+csr! {
+    /// Timer for `rdtime`.
+    pub struct Time: usize = 0xC01;
+}
+
+impl Counter for Time {}
+
+#[inline]
+#[must_use]
+pub fn read() -> u64 {
+    Time::read64()
+}
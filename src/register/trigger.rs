@@ -0,0 +1,745 @@
+use super::{Csr, csr};
+use core::ops::Range;
+
+csr! {
+    /// Trigger select register.
+    pub struct Tselect: usize = 0x7A0;
+}
+
+csr! {
+    impl Tdata1 = 0x7A1;
+}
+
+csr! {
+    /// Second trigger data register, holding the address or data a match trigger compares against.
+    pub struct Tdata2: usize = 0x7A2;
+}
+
+csr! {
+    /// Third trigger data register, holding context filters.
+    pub struct Tdata3: usize = 0x7A3;
+}
+
+csr! {
+    impl Tinfo = 0x7A4;
+}
+
+csr! {
+    impl Tcontrol = 0x7A5;
+}
+
+/// Select trigger `index`, returning whether it exists.
+///
+/// `tselect` is WARL, so a write beyond the last implemented trigger reads back differently.
+#[inline]
+pub unsafe fn select(index: usize) -> bool {
+    unsafe { Tselect::write(index) };
+    Tselect::read() == index
+}
+
+/// Trigger types supported by the selected trigger, or [`None`] if it does not exist.
+#[inline]
+#[must_use]
+pub fn info() -> Option<Tinfo> {
+    let tinfo = Tinfo::read();
+    (tinfo.0 & 0xFFFF != 1).then_some(tinfo)
+}
+
+/// Count the implemented triggers.
+///
+/// Follows the enumeration sequence from the debug specification, which requires `tinfo` to be implemented. The
+/// previous `tselect` is restored afterwards.
+#[inline]
+pub unsafe fn count() -> usize {
+    unsafe {
+        let tselect = Tselect::read();
+        let mut count = 0;
+        while select(count) && info().is_some() {
+            count += 1;
+        }
+        Tselect::write(tselect);
+        count
+    }
+}
+
+/// Install `trigger` in slot `index`.
+///
+/// The slot is disabled while `tdata2` is written, so a half-written trigger never fires. If the slot does not accept
+/// the requested type it is left disabled.
+#[inline]
+pub unsafe fn install(index: usize, trigger: Trigger) -> Result<(), Error> {
+    unsafe {
+        if !select(index) {
+            return Err(Error::OutOfRange(index));
+        }
+
+        let tdata1 = match trigger {
+            Trigger::Match { control, value } => {
+                Tdata1::write(Tdata1(0));
+                Tdata2::write(value);
+                Tdata1::from(control)
+            }
+            Trigger::Icount(icount) => Tdata1::from(icount),
+        };
+
+        Tdata1::write(tdata1);
+        if Tdata1::read().kind() != tdata1.kind() {
+            Tdata1::write(Tdata1(0));
+            return Err(Error::Unsupported(index));
+        }
+    }
+    Ok(())
+}
+
+/// Install a trigger matching any address in `range` in slots `index` and `index + 1`, chained together.
+#[inline]
+pub unsafe fn install_range(index: usize, control: Mcontrol6, range: Range<usize>) -> Result<(), Error> {
+    let lower = Trigger::Match { control: control.with_match(Match::GreaterEqual).with_chain(true), value: range.start };
+    let upper = Trigger::Match { control: control.with_match(Match::Less).with_chain(false), value: range.end };
+
+    unsafe {
+        install(index, lower)?;
+        if let Err(error) = install(index + 1, upper) {
+            remove(index);
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// Disable the trigger in slot `index`.
+#[inline]
+pub unsafe fn remove(index: usize) {
+    unsafe {
+        if select(index) {
+            Tdata1::write(Tdata1(0));
+        }
+    }
+}
+
+/// Let triggers with a breakpoint action fire in machine mode.
+#[inline]
+pub unsafe fn set_mte() {
+    unsafe { Tcontrol::set_const::<{ Tcontrol::MTE }>() };
+}
+
+#[inline]
+pub unsafe fn clear_mte() {
+    unsafe { Tcontrol::clear_const::<{ Tcontrol::MTE }>() };
+}
+
+/// Trigger to install in a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    /// Address or data match, comparing against `value`.
+    Match { control: Mcontrol6, value: usize },
+    /// Instruction count, used for single-stepping.
+    Icount(Icount),
+}
+
+impl Trigger {
+    /// Match accesses to exactly `address`.
+    #[inline]
+    pub const fn exact(control: Mcontrol6, address: usize) -> Self {
+        Self::Match { control: control.with_match(Match::Equal), value: address }
+    }
+
+    /// Match accesses to the `size` bytes at `base`. `size` must be a power of two of at least two and `base` aligned
+    /// to it.
+    #[inline]
+    pub const fn napot(control: Mcontrol6, base: usize, size: usize) -> Result<Self, Error> {
+        if !size.is_power_of_two() || size < 2 {
+            return Err(Error::InvalidSize(size));
+        }
+        if !base.is_multiple_of(size) {
+            return Err(Error::Misaligned(base));
+        }
+        Ok(Self::Match { control: control.with_match(Match::Napot), value: base | (size / 2 - 1) })
+    }
+
+    /// Fire after a single instruction retires in one of the privilege levels enabled in `icount`.
+    #[inline]
+    pub const fn single_step(icount: Icount) -> Self {
+        Self::Icount(icount.with_count(1))
+    }
+}
+
+const TYPE: usize = usize::BITS as usize - 4;
+const DMODE: usize = 1 << (usize::BITS - 5);
+
+/// First trigger data register, holding the type and configuration of the selected trigger.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tdata1(usize);
+
+impl Tdata1 {
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Trigger type, or [`None`] for a reserved or custom encoding.
+    #[inline]
+    pub fn kind(self) -> Option<Type> {
+        Type::try_from(self.0 >> TYPE).ok()
+    }
+
+    /// Only debug mode may write the trigger.
+    #[inline]
+    pub const fn dmode(self) -> bool {
+        self.0 & DMODE != 0
+    }
+
+    #[inline]
+    pub fn mcontrol6(self) -> Option<Mcontrol6> {
+        (self.kind() == Some(Type::Mcontrol6)).then_some(Mcontrol6(self.0))
+    }
+
+    #[inline]
+    pub fn icount(self) -> Option<Icount> {
+        (self.kind() == Some(Type::Icount)).then_some(Icount(self.0))
+    }
+}
+
+impl From<usize> for Tdata1 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Tdata1> for usize {
+    #[inline]
+    fn from(tdata1: Tdata1) -> Self {
+        tdata1.0
+    }
+}
+
+impl From<Mcontrol6> for Tdata1 {
+    #[inline]
+    fn from(mcontrol6: Mcontrol6) -> Self {
+        Self(mcontrol6.0)
+    }
+}
+
+impl From<Icount> for Tdata1 {
+    #[inline]
+    fn from(icount: Icount) -> Self {
+        Self(icount.0)
+    }
+}
+
+/// Trigger type in `tdata1`.
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    /// No trigger exists in this slot.
+    None = 0,
+    /// Legacy SiFive address match trigger.
+    Legacy = 1,
+    /// Address or data match trigger from debug specification 0.13.
+    Mcontrol = 2,
+    /// Instruction count trigger.
+    Icount = 3,
+    /// Interrupt trigger.
+    Itrigger = 4,
+    /// Exception trigger.
+    Etrigger = 5,
+    /// Address or data match trigger.
+    Mcontrol6 = 6,
+    /// External trigger input.
+    Tmexttrigger = 7,
+    /// Trigger exists but is disabled.
+    Disabled = 15,
+}
+
+impl TryFrom<usize> for Type {
+    type Error = ();
+
+    fn try_from(kind: usize) -> Result<Self, Self::Error> {
+        Ok(match kind {
+            0 => Self::None,
+            1 => Self::Legacy,
+            2 => Self::Mcontrol,
+            3 => Self::Icount,
+            4 => Self::Itrigger,
+            5 => Self::Etrigger,
+            6 => Self::Mcontrol6,
+            7 => Self::Tmexttrigger,
+            15 => Self::Disabled,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Configuration of an address or data match trigger.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mcontrol6(usize);
+
+impl Mcontrol6 {
+    const LOAD: usize = 1 << 0;
+    const STORE: usize = 1 << 1;
+    const EXECUTE: usize = 1 << 2;
+    const U: usize = 1 << 3;
+    const S: usize = 1 << 4;
+    const M: usize = 1 << 6;
+    const MATCH: usize = 7;
+    const CHAIN: usize = 1 << 11;
+    const ACTION: usize = 12;
+    const HIT0: usize = 1 << 22;
+    const VU: usize = 1 << 23;
+    const VS: usize = 1 << 24;
+    const HIT1: usize = 1 << 25;
+
+    /// Trigger that matches nothing and raises a breakpoint exception.
+    #[inline]
+    pub const fn new() -> Self {
+        Self((Type::Mcontrol6 as usize) << TYPE)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: usize, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    /// Match the address of loads.
+    #[inline]
+    pub const fn load(self) -> bool {
+        self.0 & Self::LOAD != 0
+    }
+
+    #[inline]
+    pub const fn with_load(self, load: bool) -> Self {
+        self.with_bit(Self::LOAD, load)
+    }
+
+    /// Match the address of stores.
+    #[inline]
+    pub const fn store(self) -> bool {
+        self.0 & Self::STORE != 0
+    }
+
+    #[inline]
+    pub const fn with_store(self, store: bool) -> Self {
+        self.with_bit(Self::STORE, store)
+    }
+
+    /// Match the address of executed instructions.
+    #[inline]
+    pub const fn execute(self) -> bool {
+        self.0 & Self::EXECUTE != 0
+    }
+
+    #[inline]
+    pub const fn with_execute(self, execute: bool) -> Self {
+        self.with_bit(Self::EXECUTE, execute)
+    }
+
+    /// Enabled in user mode.
+    #[inline]
+    pub const fn u(self) -> bool {
+        self.0 & Self::U != 0
+    }
+
+    #[inline]
+    pub const fn with_u(self, u: bool) -> Self {
+        self.with_bit(Self::U, u)
+    }
+
+    /// Enabled in supervisor mode.
+    #[inline]
+    pub const fn s(self) -> bool {
+        self.0 & Self::S != 0
+    }
+
+    #[inline]
+    pub const fn with_s(self, s: bool) -> Self {
+        self.with_bit(Self::S, s)
+    }
+
+    /// Enabled in machine mode, subject to [`Tcontrol::mte`] for breakpoint actions.
+    #[inline]
+    pub const fn m(self) -> bool {
+        self.0 & Self::M != 0
+    }
+
+    #[inline]
+    pub const fn with_m(self, m: bool) -> Self {
+        self.with_bit(Self::M, m)
+    }
+
+    /// Enabled in virtual user mode.
+    #[inline]
+    pub const fn vu(self) -> bool {
+        self.0 & Self::VU != 0
+    }
+
+    #[inline]
+    pub const fn with_vu(self, vu: bool) -> Self {
+        self.with_bit(Self::VU, vu)
+    }
+
+    /// Enabled in virtual supervisor mode.
+    #[inline]
+    pub const fn vs(self) -> bool {
+        self.0 & Self::VS != 0
+    }
+
+    #[inline]
+    pub const fn with_vs(self, vs: bool) -> Self {
+        self.with_bit(Self::VS, vs)
+    }
+
+    /// Comparison against `tdata2`, or [`None`] for a reserved encoding.
+    #[inline]
+    pub fn matching(self) -> Option<Match> {
+        Match::try_from((self.0 >> Self::MATCH) & 0xF).ok()
+    }
+
+    #[inline]
+    pub const fn with_match(self, matching: Match) -> Self {
+        Self((self.0 & !(0xF << Self::MATCH)) | ((matching as usize) << Self::MATCH))
+    }
+
+    /// Only fire if the next trigger also matches.
+    #[inline]
+    pub const fn chain(self) -> bool {
+        self.0 & Self::CHAIN != 0
+    }
+
+    #[inline]
+    pub const fn with_chain(self, chain: bool) -> Self {
+        self.with_bit(Self::CHAIN, chain)
+    }
+
+    /// Action taken when the trigger fires, or [`None`] for a reserved encoding.
+    #[inline]
+    pub fn action(self) -> Option<Action> {
+        Action::try_from((self.0 >> Self::ACTION) & 0xF).ok()
+    }
+
+    #[inline]
+    pub const fn with_action(self, action: Action) -> Self {
+        Self((self.0 & !(0xF << Self::ACTION)) | ((action as usize) << Self::ACTION))
+    }
+
+    /// The trigger has fired since the flag was last cleared.
+    #[inline]
+    pub const fn hit(self) -> bool {
+        self.0 & (Self::HIT0 | Self::HIT1) != 0
+    }
+}
+
+impl Default for Mcontrol6 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Comparison performed by a match trigger.
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Match {
+    /// Value equals `tdata2`.
+    Equal = 0,
+    /// Value lies in the naturally aligned power-of-two range encoded in `tdata2`.
+    Napot = 1,
+    /// Value is greater than or equal to `tdata2`.
+    GreaterEqual = 2,
+    /// Value is less than `tdata2`.
+    Less = 3,
+    /// Low half of the value, masked by the high half of `tdata2`, equals the low half of `tdata2`.
+    MaskLow = 4,
+    /// High half of the value, masked by the high half of `tdata2`, equals the low half of `tdata2`.
+    MaskHigh = 5,
+    NotEqual = 8,
+    NotNapot = 9,
+    NotMaskLow = 12,
+    NotMaskHigh = 13,
+}
+
+impl TryFrom<usize> for Match {
+    type Error = ();
+
+    fn try_from(matching: usize) -> Result<Self, Self::Error> {
+        Ok(match matching {
+            0 => Self::Equal,
+            1 => Self::Napot,
+            2 => Self::GreaterEqual,
+            3 => Self::Less,
+            4 => Self::MaskLow,
+            5 => Self::MaskHigh,
+            8 => Self::NotEqual,
+            9 => Self::NotNapot,
+            12 => Self::NotMaskLow,
+            13 => Self::NotMaskHigh,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Action taken when a trigger fires.
+#[repr(usize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Raise a breakpoint exception, reported as [`Exception::Breakpoint`](super::scause::Exception::Breakpoint).
+    Breakpoint = 0,
+    /// Enter debug mode.
+    DebugMode = 1,
+    TraceOn = 2,
+    TraceOff = 3,
+    TraceNotify = 4,
+    External0 = 8,
+    External1 = 9,
+}
+
+impl TryFrom<usize> for Action {
+    type Error = ();
+
+    fn try_from(action: usize) -> Result<Self, Self::Error> {
+        Ok(match action {
+            0 => Self::Breakpoint,
+            1 => Self::DebugMode,
+            2 => Self::TraceOn,
+            3 => Self::TraceOff,
+            4 => Self::TraceNotify,
+            8 => Self::External0,
+            9 => Self::External1,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Configuration of an instruction count trigger.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icount(usize);
+
+impl Icount {
+    const ACTION: usize = 0x3F;
+    const U: usize = 1 << 6;
+    const S: usize = 1 << 7;
+    const PENDING: usize = 1 << 8;
+    const M: usize = 1 << 9;
+    const COUNT: usize = 10;
+    const COUNT_MASK: usize = 0x3FFF;
+    const HIT: usize = 1 << 24;
+    const VU: usize = 1 << 25;
+    const VS: usize = 1 << 26;
+
+    /// Trigger that fires after `count` instructions and raises a breakpoint exception. Out-of-range `count` bits are
+    /// truncated.
+    #[inline]
+    pub const fn new(count: usize) -> Self {
+        Self((Type::Icount as usize) << TYPE).with_count(count)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    const fn with_bit(self, mask: usize, value: bool) -> Self {
+        if value { Self(self.0 | mask) } else { Self(self.0 & !mask) }
+    }
+
+    /// Instructions left before the trigger fires.
+    #[inline]
+    pub const fn count(self) -> usize {
+        (self.0 >> Self::COUNT) & Self::COUNT_MASK
+    }
+
+    #[inline]
+    pub const fn with_count(self, count: usize) -> Self {
+        Self((self.0 & !(Self::COUNT_MASK << Self::COUNT)) | ((count & Self::COUNT_MASK) << Self::COUNT))
+    }
+
+    /// Enabled in user mode.
+    #[inline]
+    pub const fn u(self) -> bool {
+        self.0 & Self::U != 0
+    }
+
+    #[inline]
+    pub const fn with_u(self, u: bool) -> Self {
+        self.with_bit(Self::U, u)
+    }
+
+    /// Enabled in supervisor mode.
+    #[inline]
+    pub const fn s(self) -> bool {
+        self.0 & Self::S != 0
+    }
+
+    #[inline]
+    pub const fn with_s(self, s: bool) -> Self {
+        self.with_bit(Self::S, s)
+    }
+
+    /// Enabled in machine mode.
+    #[inline]
+    pub const fn m(self) -> bool {
+        self.0 & Self::M != 0
+    }
+
+    #[inline]
+    pub const fn with_m(self, m: bool) -> Self {
+        self.with_bit(Self::M, m)
+    }
+
+    /// Enabled in virtual user mode.
+    #[inline]
+    pub const fn vu(self) -> bool {
+        self.0 & Self::VU != 0
+    }
+
+    #[inline]
+    pub const fn with_vu(self, vu: bool) -> Self {
+        self.with_bit(Self::VU, vu)
+    }
+
+    /// Enabled in virtual supervisor mode.
+    #[inline]
+    pub const fn vs(self) -> bool {
+        self.0 & Self::VS != 0
+    }
+
+    #[inline]
+    pub const fn with_vs(self, vs: bool) -> Self {
+        self.with_bit(Self::VS, vs)
+    }
+
+    /// Action taken when the trigger fires, or [`None`] for a reserved encoding.
+    #[inline]
+    pub fn action(self) -> Option<Action> {
+        Action::try_from(self.0 & Self::ACTION).ok()
+    }
+
+    #[inline]
+    pub const fn with_action(self, action: Action) -> Self {
+        Self((self.0 & !Self::ACTION) | action as usize)
+    }
+
+    /// The count reached zero and the trigger will fire once the hart returns to an enabled privilege level.
+    #[inline]
+    pub const fn pending(self) -> bool {
+        self.0 & Self::PENDING != 0
+    }
+
+    /// The trigger has fired since the flag was last cleared.
+    #[inline]
+    pub const fn hit(self) -> bool {
+        self.0 & Self::HIT != 0
+    }
+}
+
+/// Trigger information register, describing the selected trigger.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tinfo(usize);
+
+impl Tinfo {
+    const VERSION: usize = 24;
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// The trigger can be configured as `kind`.
+    #[inline]
+    pub const fn supports(self, kind: Type) -> bool {
+        self.0 & (1 << kind as usize) & 0xFFFF != 0
+    }
+
+    /// Version of the debug specification the trigger implements. 0 for 0.13 and 1 for 1.0.
+    #[inline]
+    pub const fn version(self) -> u8 {
+        (self.0 >> Self::VERSION) as u8
+    }
+}
+
+impl From<usize> for Tinfo {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Tinfo> for usize {
+    #[inline]
+    fn from(tinfo: Tinfo) -> Self {
+        tinfo.0
+    }
+}
+
+/// Trigger control register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tcontrol(usize);
+
+impl Tcontrol {
+    const MTE: usize = 1 << 3;
+    const MPTE: usize = 1 << 7;
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Machine-mode trigger enable. Cleared on a trap into machine mode, so a trigger cannot fire inside its own handler.
+    #[inline]
+    pub const fn mte(self) -> bool {
+        self.0 & Self::MTE != 0
+    }
+
+    #[inline]
+    pub const fn set_mte(&mut self, mte: bool) {
+        if mte { self.0 |= Self::MTE } else { self.0 &= !Self::MTE }
+    }
+
+    /// Machine-mode trigger enable prior to the trap. Restored into [`Tcontrol::mte`] by `mret`.
+    #[inline]
+    pub const fn mpte(self) -> bool {
+        self.0 & Self::MPTE != 0
+    }
+
+    #[inline]
+    pub const fn set_mpte(&mut self, mpte: bool) {
+        if mpte { self.0 |= Self::MPTE } else { self.0 &= !Self::MPTE }
+    }
+}
+
+impl From<usize> for Tcontrol {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Tcontrol> for usize {
+    #[inline]
+    fn from(tcontrol: Tcontrol) -> Self {
+        tcontrol.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("trigger {0} does not exist")]
+    OutOfRange(usize),
+    #[error("trigger {0} does not support the requested type")]
+    Unsupported(usize),
+    #[error("trigger address {0:#x} is not sufficiently aligned")]
+    Misaligned(usize),
+    #[error("invalid NAPOT trigger size: {0:#x}")]
+    InvalidSize(usize),
+}
@@ -0,0 +1,65 @@
+use super::vxrm::RoundingMode;
+use super::{Csr, csr};
+
+csr! {
+    impl Vcsr = 0x00F;
+}
+
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> Vcsr {
+    Vcsr::read()
+}
+
+#[inline]
+#[target_feature(enable = "v")]
+pub fn write(vcsr: Vcsr) {
+    unsafe { Vcsr::write(vcsr) };
+}
+
+/// Vector control and status register, combining `vxrm` and `vxsat`.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vcsr(u8);
+
+impl Vcsr {
+    const VXSAT: u8 = 1 << 0;
+    const VXRM: u8 = 1;
+
+    #[inline]
+    pub const fn new(vxrm: RoundingMode, vxsat: bool) -> Self {
+        Self(((vxrm as u8) << Self::VXRM) | vxsat as u8)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Fixed-point rounding mode.
+    #[inline]
+    pub fn vxrm(self) -> RoundingMode {
+        RoundingMode::from((self.0 >> Self::VXRM) as usize)
+    }
+
+    /// Fixed-point saturation flag.
+    #[inline]
+    pub const fn vxsat(self) -> bool {
+        self.0 & Self::VXSAT != 0
+    }
+}
+
+impl From<usize> for Vcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits as u8 & 0b111)
+    }
+}
+
+impl From<Vcsr> for usize {
+    #[inline]
+    fn from(vcsr: Vcsr) -> Self {
+        vcsr.0 as usize
+    }
+}
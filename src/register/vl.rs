@@ -0,0 +1,14 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Vector length register.
+    pub struct Vl: usize = 0xC20;
+}
+
+/// Read the number of elements processed by vector instructions.
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> usize {
+    Vl::read()
+}
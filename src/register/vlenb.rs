@@ -0,0 +1,14 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Vector register length in bytes.
+    pub struct Vlenb: usize = 0xC22;
+}
+
+/// Read VLEN/8, the size of a single vector register in bytes.
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> usize {
+    Vlenb::read()
+}
@@ -0,0 +1,18 @@
+use super::satp::Satp;
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor address translation and protection register, seen as `satp` by a guest.
+    pub struct Vsatp: Satp = 0x280;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Satp {
+    Vsatp::read()
+}
+
+#[inline]
+pub unsafe fn write(vsatp: Satp) {
+    unsafe { Vsatp::write(vsatp) };
+}
@@ -0,0 +1,19 @@
+use super::scause::Cause;
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor trap cause register, seen as `scause` by a guest.
+    pub struct Vscause: usize = 0x242;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Cause {
+    Cause::from_bits(Vscause::read())
+}
+
+/// Set the cause reported to the guest's trap handler.
+#[inline]
+pub unsafe fn write(cause: usize) {
+    unsafe { Vscause::write(cause) };
+}
@@ -0,0 +1,17 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor exception program counter, seen as `sepc` by a guest.
+    pub struct Vsepc: usize = 0x241;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> usize {
+    Vsepc::read()
+}
+
+#[inline]
+pub unsafe fn write(vsepc: usize) {
+    unsafe { Vsepc::write(vsepc & !0b1) };
+}
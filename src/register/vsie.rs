@@ -0,0 +1,18 @@
+use super::sie::Sie;
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor interrupt-enable register, seen as `sie` by a guest.
+    pub struct Vsie: Sie = 0x204;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Sie {
+    Vsie::read()
+}
+
+#[inline]
+pub unsafe fn write(vsie: Sie) {
+    unsafe { Vsie::write(vsie) };
+}
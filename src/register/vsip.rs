@@ -0,0 +1,13 @@
+use super::sip::Sip;
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor interrupt-pending register, seen as `sip` by a guest.
+    pub struct Vsip: Sip = 0x244;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Sip {
+    Vsip::read()
+}
@@ -0,0 +1,17 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor scratch register, seen as `sscratch` by a guest.
+    pub struct Vsscratch: usize = 0x240;
+}
+
+#[must_use]
+#[inline]
+pub fn read() -> usize {
+    Vsscratch::read()
+}
+
+#[inline]
+pub fn write(data: usize) {
+    unsafe { Vsscratch::write(data) };
+}
@@ -0,0 +1,18 @@
+use super::sstatus::Sstatus;
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor status register, seen as `sstatus` by a guest.
+    pub struct Vsstatus: Sstatus = 0x200;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Sstatus {
+    Vsstatus::read()
+}
+
+#[inline]
+pub unsafe fn write(vsstatus: Sstatus) {
+    unsafe { Vsstatus::write(vsstatus) };
+}
@@ -0,0 +1,20 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Vector start position register.
+    pub struct Vstart: usize = 0x008;
+}
+
+/// Read the index of the first element to be executed by a vector instruction.
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> usize {
+    Vstart::read()
+}
+
+#[inline]
+#[target_feature(enable = "v")]
+pub unsafe fn write(vstart: usize) {
+    unsafe { Vstart::write(vstart) };
+}
@@ -0,0 +1,27 @@
+use super::scause::Cause;
+use super::stval::Tval;
+use super::{Csr, csr};
+use crate::address::Virtual;
+
+csr! {
+    /// Virtual supervisor trap value register, seen as `stval` by a guest.
+    pub struct Vstval: usize = 0x243;
+}
+
+#[inline]
+#[must_use]
+pub fn read() -> Option<Virtual> {
+    Virtual::new(Vstval::read())
+}
+
+/// Read `vstval` and interpret it according to the `cause` of the trap.
+#[inline]
+#[must_use]
+pub fn read_for(cause: Cause) -> Tval {
+    Tval::new(cause, Vstval::read())
+}
+
+#[inline]
+pub unsafe fn write(vstval: usize) {
+    unsafe { Vstval::write(vstval) };
+}
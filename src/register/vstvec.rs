@@ -0,0 +1,17 @@
+use super::stvec::{Error, Mode};
+use super::{Csr, csr};
+
+csr! {
+    /// Virtual supervisor trap vector base address register, seen as `stvec` by a guest.
+    pub struct Vstvec: usize = 0x205;
+}
+
+#[inline(always)]
+pub fn read() -> Result<Mode, Error> {
+    Mode::try_from(Vstvec::read())
+}
+
+#[inline(always)]
+pub unsafe fn write(mode: Mode) {
+    unsafe { Vstvec::write(mode.into()) };
+}
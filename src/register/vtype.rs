@@ -0,0 +1,81 @@
+use super::{Csr, csr};
+use crate::{LMUL, SEW};
+
+csr! {
+    impl VType = 0xC21;
+}
+
+/// Read the vector type set by the last `vsetvl{i}`.
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> VType {
+    VType::read()
+}
+
+/// Vector data type register.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VType(usize);
+
+impl VType {
+    const VSEW: usize = 3;
+    const VTA: usize = 1 << 6;
+    const VMA: usize = 1 << 7;
+    const VILL: usize = 1 << (usize::BITS - 1);
+
+    /// Encode a vector type as accepted by `vsetvl`.
+    #[inline]
+    pub const fn new(sew: SEW, lmul: LMUL, tail_agnostic: bool, mask_agnostic: bool) -> Self {
+        Self(((mask_agnostic as usize) << 7) | ((tail_agnostic as usize) << 6) | ((sew as usize) << Self::VSEW) | (lmul as usize))
+    }
+
+    #[inline]
+    pub const fn bits(self) -> usize {
+        self.0
+    }
+
+    /// Selected element width, or [`None`] if reserved or [`VType::vill`] is set.
+    #[inline]
+    pub fn sew(self) -> Option<SEW> {
+        if self.vill() { None } else { SEW::try_from((self.0 >> Self::VSEW) & 0b111).ok() }
+    }
+
+    /// Register group multiplier, or [`None`] if reserved or [`VType::vill`] is set.
+    #[inline]
+    pub fn lmul(self) -> Option<LMUL> {
+        if self.vill() { None } else { LMUL::try_from(self.0 & 0b111).ok() }
+    }
+
+    /// Tail elements may be overwritten with all ones.
+    #[inline]
+    pub const fn tail_agnostic(self) -> bool {
+        self.0 & Self::VTA != 0
+    }
+
+    /// Masked-off elements may be overwritten with all ones.
+    #[inline]
+    pub const fn mask_agnostic(self) -> bool {
+        self.0 & Self::VMA != 0
+    }
+
+    /// The last `vsetvl{i}` requested an unsupported type; vector instructions other than `vsetvl{i}` are illegal.
+    #[inline]
+    pub const fn vill(self) -> bool {
+        self.0 & Self::VILL != 0
+    }
+}
+
+impl From<usize> for VType {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<VType> for usize {
+    #[inline]
+    fn from(vtype: VType) -> Self {
+        vtype.0
+    }
+}
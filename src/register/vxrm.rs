@@ -0,0 +1,52 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Vector fixed-point rounding mode register.
+    pub struct Vxrm: usize = 0x00A;
+}
+
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> RoundingMode {
+    RoundingMode::from(Vxrm::read())
+}
+
+#[inline]
+#[target_feature(enable = "v")]
+pub fn write(mode: RoundingMode) {
+    unsafe { Vxrm::write(mode.into()) };
+}
+
+/// Fixed-point rounding mode.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties up.
+    RNU = 0b00,
+    /// Round to nearest, ties to even.
+    RNE = 0b01,
+    /// Round down, truncating.
+    RDN = 0b10,
+    /// Round to odd.
+    ROD = 0b11,
+}
+
+impl From<usize> for RoundingMode {
+    #[inline]
+    fn from(mode: usize) -> Self {
+        match mode & 0b11 {
+            0b00 => Self::RNU,
+            0b01 => Self::RNE,
+            0b10 => Self::RDN,
+            _ => Self::ROD,
+        }
+    }
+}
+
+impl From<RoundingMode> for usize {
+    #[inline]
+    fn from(mode: RoundingMode) -> Self {
+        mode as usize
+    }
+}
@@ -0,0 +1,20 @@
+use super::{Csr, csr};
+
+csr! {
+    /// Vector fixed-point saturation flag register.
+    pub struct Vxsat: usize = 0x009;
+}
+
+/// Whether a fixed-point instruction saturated since the flag was last cleared.
+#[inline]
+#[must_use]
+#[target_feature(enable = "v")]
+pub fn read() -> bool {
+    Vxsat::read() & 1 != 0
+}
+
+#[inline]
+#[target_feature(enable = "v")]
+pub fn clear() {
+    unsafe { Vxsat::clear_const::<1>() };
+}